@@ -0,0 +1,143 @@
+//! Minimal Token-2022 transfer hook for exercising hook forwarding in tests.
+//!
+//! The hook requires one extra account, a per-mint counter PDA, and bumps it on
+//! every transfer. A transfer that does not forward the extra accounts fails.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+    state::Account as Token2022Account,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+declare_id!("BCdqQYzzBDXpsMopmzdFfjhCYKv3KqtUn3NEDLLKuhc5");
+
+#[program]
+pub mod sample_transfer_hook {
+    use super::*;
+
+    /// Create the mint's validation account (one extra account: the counter PDA)
+    /// and the counter itself
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas()?,
+        )?;
+        ctx.accounts.counter.count = 0;
+        Ok(())
+    }
+
+    /// Transfer hook entry point, invoked by Token-2022 during transfer_checked
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn execute(ctx: Context<Execute>, _amount: u64) -> Result<()> {
+        // Only Token-2022 sets the transferring flag, so the hook can't be called directly
+        let source = ctx.accounts.source_token.to_account_info();
+        let data = source.try_borrow_data()?;
+        let account = StateWithExtensions::<Token2022Account>::unpack(&data)?;
+        let extension = account.get_extension::<TransferHookAccount>()?;
+        require!(
+            bool::from(extension.transferring),
+            HookError::NotTransferring
+        );
+
+        ctx.accounts.counter.count = ctx.accounts.counter.count.saturating_add(1);
+        Ok(())
+    }
+}
+
+/// Counter PDA: ["counter", mint]
+fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: Counter::SEED.to_vec(),
+            },
+            // Execute account index 1 is the mint
+            Seed::AccountKey { index: 1 },
+        ],
+        false,
+        true,
+    )?])
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Validation account, written as a TLV ExtraAccountMetaList
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Counter::INIT_SPACE,
+        seeds = [Counter::SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub counter: Account<'info, Counter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account order fixed by the transfer hook interface
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Source owner or delegate (sender, Delegate PDA or Pool PDA)
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validation account, address enforced by seeds
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Counter::SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub counter: Account<'info, Counter>,
+}
+
+/// Transfers seen by the hook for a mint
+#[account]
+#[derive(InitSpace)]
+pub struct Counter {
+    pub count: u64,
+}
+
+impl Counter {
+    pub const SEED: &'static [u8] = b"counter";
+}
+
+#[error_code]
+pub enum HookError {
+    #[msg("Hook called outside of a Token-2022 transfer")]
+    NotTransferring,
+}
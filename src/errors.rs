@@ -46,4 +46,166 @@ pub enum PaymentError {
 
     #[msg("Amount mismatch")]
     AmountMismatch,
+
+    #[msg("Pool inactive")]
+    PoolInactive,
+
+    #[msg("Invalid pool vault")]
+    InvalidPoolVault,
+
+    #[msg("Insufficient pool balance")]
+    InsufficientPoolBalance,
+
+    #[msg("Settlement exceeds owed amount")]
+    SettlementExceedsOwed,
+
+    #[msg("Invalid destination chain")]
+    InvalidDestinationChain,
+
+    #[msg("Invalid recipient address")]
+    InvalidRecipientAddress,
+
+    #[msg("Payment already refunded")]
+    PaymentAlreadyRefunded,
+
+    #[msg("Refund exceeds owed amount")]
+    RefundExceedsOwed,
+
+    #[msg("Invalid fee config")]
+    InvalidFeeConfig,
+
+    #[msg("Invalid protocol fee")]
+    InvalidProtocolFee,
+
+    #[msg("Fee wallet token account required")]
+    FeeAccountRequired,
+
+    #[msg("Merchant not registered")]
+    MerchantNotRegistered,
+
+    #[msg("Merchant suspended")]
+    MerchantSuspended,
+
+    #[msg("Invalid merchant settlement account")]
+    InvalidSettlementAccount,
+
+    #[msg("Too many settlement accounts")]
+    TooManySettlementAccounts,
+
+    #[msg("Invalid merchant metadata")]
+    InvalidMerchantMetadata,
+
+    #[msg("Invalid relayer")]
+    InvalidRelayer,
+
+    #[msg("Relayer token account required")]
+    RelayerAccountRequired,
+
+    #[msg("Invalid gas tank")]
+    InvalidGasTank,
+
+    #[msg("Insufficient gas tank balance")]
+    InsufficientGasTankBalance,
+
+    #[msg("Mint not enabled for payments")]
+    MintNotEnabled,
+
+    #[msg("Invalid mint config")]
+    InvalidMintConfig,
+
+    #[msg("Payment kind not allowed for this mint")]
+    PaymentKindNotAllowed,
+
+    #[msg("Delegated payments not allowed for this mint")]
+    DelegatedPaymentNotAllowed,
+
+    #[msg("Amount below mint minimum")]
+    AmountBelowMinimum,
+
+    #[msg("Amount above mint maximum")]
+    AmountAboveMaximum,
+
+    #[msg("Payment reference too long")]
+    InvalidReference,
+
+    #[msg("Invalid reference key count")]
+    InvalidReferenceKeys,
+
+    #[msg("No pending transfer")]
+    NoPendingTransfer,
+
+    #[msg("Action ETA earlier than timelock delay")]
+    InvalidActionEta,
+
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+
+    #[msg("Action not ready to execute")]
+    ActionNotReady,
+
+    #[msg("Action accounts do not match the action kind")]
+    MissingActionAccount,
+
+    #[msg("Invalid council members or threshold")]
+    InvalidCouncil,
+
+    #[msg("Not a council member")]
+    NotCouncilMember,
+
+    #[msg("Proposal already approved by this member")]
+    AlreadyApproved,
+
+    #[msg("Proposal approval threshold not met")]
+    ThresholdNotMet,
+
+    #[msg("Invalid proposal accounts or data")]
+    InvalidProposal,
+
+    #[msg("Direct payments paused")]
+    DirectPaymentsPaused,
+
+    #[msg("Delegated direct payments paused")]
+    DirectDelegatedPaymentsPaused,
+
+    #[msg("Pool payments paused")]
+    PoolPaymentsPaused,
+
+    #[msg("Delegated pool payments paused")]
+    PoolDelegatedPaymentsPaused,
+
+    #[msg("Mint paused")]
+    MintPaused,
+
+    #[msg("Server signer paused")]
+    ServerSignerPaused,
+
+    #[msg("Invalid pause scope")]
+    InvalidPauseScope,
+
+    #[msg("Unrecognized account layout")]
+    InvalidAccountLayout,
+
+    #[msg("Account already at the current version")]
+    AlreadyMigrated,
+
+    #[msg("Server signer already in the requested state")]
+    InvalidServerSignerStatus,
+
+    #[msg("Server signer registry is full")]
+    TooManyServerSigners,
+
+    #[msg("Invalid maximum number of server signers")]
+    InvalidMaxSigners,
+
+    #[msg("Payment already settled")]
+    PaymentAlreadySettled,
+
+    #[msg("Invalid settlement payments")]
+    InvalidSettlementPayments,
+
+    #[msg("Payment record required with a gas tank")]
+    PaymentRecordRequired,
+
+    #[msg("Server signer already registered")]
+    SignerAlreadyRegistered,
 }
@@ -0,0 +1,481 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::PauseScope;
+use crate::instructions::TransferFeeMode;
+use crate::state::{AddressFormat, AdminActionKind, MemoFormat, MerchantStatus, Role};
+
+// ============================================
+// Payments
+// ============================================
+
+/// How a payment moved tokens out of the sender's account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentKind {
+    /// Direct payment, sender signs
+    Direct,
+    /// Direct payment, Delegate PDA pulls
+    DirectDelegated,
+    /// Pool payment, sender signs
+    Pool,
+    /// Pool payment, Delegate PDA pulls
+    PoolDelegated,
+}
+
+#[event]
+pub struct DirectPaymentSettled {
+    pub payment_id: [u8; 32],
+    pub kind: PaymentKind,
+    pub sender: Pubkey,
+    /// Owner of the destination token account
+    pub recipient: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub amount: u64,
+    pub protocol_fee: u64,
+    pub fee_wallet: Pubkey,
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    /// Token-2022 transfer fee withheld across all legs
+    pub transfer_fee_withheld: u64,
+    pub server_signer: Pubkey,
+    pub reference: Vec<u8>,
+    pub reference_keys: Vec<Pubkey>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct PoolPaymentSettled {
+    pub payment_id: [u8; 32],
+    pub kind: PaymentKind,
+    pub sender: Pubkey,
+    pub pool: Pubkey,
+    /// Tracked recipient on the destination chain (EVM/SVM address)
+    pub recipient: [u8; 32],
+    pub destination_chain_id: u64,
+    pub address_format: AddressFormat,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub amount: u64,
+    pub service_fee: u64,
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    /// Token-2022 transfer fee withheld across all legs
+    pub transfer_fee_withheld: u64,
+    /// Amount credited to the recipient's ledger entry
+    pub credited_amount: u64,
+    pub server_signer: Pubkey,
+    pub reference: Vec<u8>,
+    pub reference_keys: Vec<Pubkey>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct PoolSettled {
+    pub pool: Pubkey,
+    pub recipient: [u8; 32],
+    pub destination_chain_id: u64,
+    pub address_format: AddressFormat,
+    /// Owner of the destination token account
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    /// Debited from the ledger
+    pub amount: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    pub transfer_fee_withheld: u64,
+    /// Ledger nonce consumed by this settlement
+    pub settlement_nonce: u64,
+    /// Pool payments paid out (now Settled, no longer refundable)
+    pub payment_ids: Vec<[u8; 32]>,
+    pub server_signer: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct PoolPaymentRefunded {
+    pub payment_id: [u8; 32],
+    pub pool: Pubkey,
+    pub sender: Pubkey,
+    pub sender_token_account: Pubkey,
+    pub mint: Pubkey,
+    /// amount + service fee returned from the vault
+    pub amount: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    pub transfer_fee_withheld: u64,
+    pub server_signer: Pubkey,
+    pub slot: u64,
+}
+
+// ============================================
+// Pool Admin
+// ============================================
+
+#[event]
+pub struct PoolCreated {
+    pub pool: Pubkey,
+    pub operator: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    /// Authority or Pool Operator
+    pub created_by: Pubkey,
+}
+
+#[event]
+pub struct PoolActiveChanged {
+    pub pool: Pubkey,
+    pub is_active: bool,
+    /// Authority or Pool Operator
+    pub changed_by: Pubkey,
+}
+
+#[event]
+pub struct PoolWithdrawn {
+    pub pool: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// Authority or the pool's operator
+    pub withdrawn_by: Pubkey,
+}
+
+// ============================================
+// Merchant Admin
+// ============================================
+
+#[event]
+pub struct MerchantRegistered {
+    /// Merchant wallet
+    pub merchant: Pubkey,
+    pub fee_tier: u8,
+    pub name: String,
+    pub uri: String,
+    /// Authority or Merchant Manager
+    pub registered_by: Pubkey,
+}
+
+#[event]
+pub struct MerchantUpdated {
+    pub merchant: Pubkey,
+    pub fee_tier: u8,
+    pub name: String,
+    pub uri: String,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct MerchantStatusChanged {
+    pub merchant: Pubkey,
+    pub status: MerchantStatus,
+    pub changed_by: Pubkey,
+}
+
+#[event]
+pub struct MerchantSettlementAccountSet {
+    pub merchant: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub set_by: Pubkey,
+}
+
+#[event]
+pub struct MerchantSettlementAccountRemoved {
+    pub merchant: Pubkey,
+    pub mint: Pubkey,
+    pub removed_by: Pubkey,
+}
+
+#[event]
+pub struct MerchantRegistryRequiredChanged {
+    pub required: bool,
+    pub authority: Pubkey,
+}
+
+// ============================================
+// Fee & Mint Admin
+// ============================================
+
+#[event]
+pub struct FeeConfigSet {
+    /// Default Pubkey for the global config, otherwise the per-mint override's mint
+    pub mint: Pubkey,
+    pub fee_wallet: Pubkey,
+    pub fee_bps: u16,
+    pub min_fee: u64,
+    pub max_fee: u64,
+    /// Authority or Fee Manager
+    pub set_by: Pubkey,
+}
+
+#[event]
+pub struct MintFeeConfigRemoved {
+    pub mint: Pubkey,
+    pub removed_by: Pubkey,
+}
+
+#[event]
+pub struct MintConfigSet {
+    pub mint: Pubkey,
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub allowed_kinds: u8,
+    pub allow_delegated: bool,
+}
+
+#[event]
+pub struct MintConfigRemoved {
+    pub mint: Pubkey,
+}
+
+// ============================================
+// Admin
+// ============================================
+
+#[event]
+pub struct Initialized {
+    pub authority: Pubkey,
+    pub emergency_admin: Pubkey,
+    /// Initial server signer
+    pub server_signer: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct Paused {
+    /// Emergency admin or Pauser
+    pub pauser: Pubkey,
+    pub scope: PauseScope,
+}
+
+#[event]
+pub struct Unpaused {
+    /// Emergency admin or Pauser
+    pub pauser: Pubkey,
+    pub scope: PauseScope,
+}
+
+#[event]
+pub struct EmergencyAdminChangeProposed {
+    pub authority: Pubkey,
+    pub pending_emergency_admin: Pubkey,
+}
+
+#[event]
+pub struct EmergencyAdminChangeCancelled {
+    pub authority: Pubkey,
+    pub cancelled_emergency_admin: Pubkey,
+}
+
+/// Emitted when the pending emergency admin accepts
+#[event]
+pub struct EmergencyAdminChanged {
+    pub old_emergency_admin: Pubkey,
+    pub new_emergency_admin: Pubkey,
+}
+
+#[event]
+pub struct ServerSignerAdded {
+    pub signer: Pubkey,
+    /// Authority, or emergency admin when `emergency`
+    pub added_by: Pubkey,
+    pub emergency: bool,
+}
+
+#[event]
+pub struct ServerSignerDeactivated {
+    pub signer: Pubkey,
+    /// Authority, emergency admin or Signer Manager
+    pub deactivated_by: Pubkey,
+}
+
+#[event]
+pub struct ServerSignerReactivated {
+    pub signer: Pubkey,
+    pub reactivated_by: Pubkey,
+}
+
+#[event]
+pub struct ServerSignerRemoved {
+    pub signer: Pubkey,
+    /// Authority, or emergency admin when `emergency`
+    pub removed_by: Pubkey,
+    pub emergency: bool,
+}
+
+#[event]
+pub struct SignerRegistryCreated {
+    pub max_signers: u8,
+    /// Existing server signers registered at creation
+    pub signer_count: u8,
+}
+
+#[event]
+pub struct SignerRegistryMaxSignersChanged {
+    pub old_max_signers: u8,
+    pub new_max_signers: u8,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+    pub granted_by: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+    pub revoked_by: Pubkey,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    /// 0 = pre-versioning layout
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+/// Emitted when the pending authority accepts
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct MemoFormatChanged {
+    pub old_memo_format: MemoFormat,
+    pub new_memo_format: MemoFormat,
+}
+
+// ============================================
+// Timelock
+// ============================================
+
+#[event]
+pub struct ActionScheduled {
+    pub id: u64,
+    pub kind: AdminActionKind,
+    pub target: Pubkey,
+    pub delay: i64,
+    pub eta: i64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub id: u64,
+    pub kind: AdminActionKind,
+    pub target: Pubkey,
+    pub delay: i64,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub id: u64,
+    pub kind: AdminActionKind,
+    pub target: Pubkey,
+    /// Authority or emergency admin
+    pub cancelled_by: Pubkey,
+}
+
+// ============================================
+// Council
+// ============================================
+
+#[event]
+pub struct CouncilUpdated {
+    pub council_authority: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct CouncilProposalCreated {
+    pub id: u64,
+    pub proposer: Pubkey,
+    /// Proposed instruction data
+    pub data: Vec<u8>,
+}
+
+#[event]
+pub struct CouncilProposalApproved {
+    pub id: u64,
+    pub member: Pubkey,
+    /// Approvals from current members, including this one
+    pub approvals: u8,
+}
+
+#[event]
+pub struct CouncilProposalExecuted {
+    pub id: u64,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct CouncilProposalCancelled {
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+// ============================================
+// Gas Tank
+// ============================================
+
+#[event]
+pub struct GasTankCreated {
+    pub merchant: Pubkey,
+    pub reimbursement_per_payment: u64,
+}
+
+#[event]
+pub struct GasTankReimbursementChanged {
+    pub merchant: Pubkey,
+    pub old_reimbursement_per_payment: u64,
+    pub new_reimbursement_per_payment: u64,
+}
+
+#[event]
+pub struct GasTankDeposited {
+    pub merchant: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    /// Lamports available for reimbursement after the deposit
+    pub balance: u64,
+}
+
+#[event]
+pub struct GasTankWithdrawn {
+    pub merchant: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    /// Lamports available for reimbursement after the withdrawal
+    pub balance: u64,
+}
+
+#[event]
+pub struct GasTankReimbursed {
+    pub merchant: Pubkey,
+    pub payer: Pubkey,
+    pub payment_id: [u8; 32],
+    pub amount: u64,
+    /// Lamports available for reimbursement after the payout
+    pub balance: u64,
+}
@@ -1,66 +1,175 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::PaymentError;
-use crate::state::{Config, ServerSigner};
+use crate::events::{
+    AuthorityTransferCancelled, AuthorityTransferred, EmergencyAdminChangeCancelled,
+    EmergencyAdminChanged, MemoFormatChanged, Paused, ServerSignerAdded, ServerSignerDeactivated,
+    ServerSignerReactivated, ServerSignerRemoved, Unpaused,
+};
+use crate::state::{
+    Config, MemoFormat, MintConfig, Role, RoleAssignment, ServerSigner, SignerRegistry,
+};
+
+/// What pause / unpause applies to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PauseScope {
+    /// Bitmask of Config::PAUSE_* flags (payment kinds, settlement)
+    Operations(u8),
+    /// A single mint (pass its mint_config)
+    Mint(Pubkey),
+    /// A single server signer (pass its server_signer_account)
+    ServerSigner(Pubkey),
+}
+
+fn apply_pause(
+    config: &mut Config,
+    mint_config: Option<&mut Account<MintConfig>>,
+    server_signer_account: Option<&mut Account<ServerSigner>>,
+    scope: PauseScope,
+    paused: bool,
+    pauser: Pubkey,
+) -> Result<()> {
+    match scope {
+        PauseScope::Operations(flags) => {
+            require!(
+                flags != 0 && flags & !Config::PAUSE_ALL == 0,
+                PaymentError::InvalidPauseScope
+            );
+            if paused {
+                config.pause_flags |= flags;
+            } else {
+                config.pause_flags &= !flags;
+            }
+        }
+        PauseScope::Mint(mint) => {
+            let mint_config = mint_config.ok_or(PaymentError::InvalidPauseScope)?;
+            require_keys_eq!(mint_config.mint, mint, PaymentError::InvalidPauseScope);
+            mint_config.paused = paused;
+        }
+        PauseScope::ServerSigner(signer) => {
+            let server_signer = server_signer_account.ok_or(PaymentError::InvalidPauseScope)?;
+            require_keys_eq!(
+                server_signer.signer,
+                signer,
+                PaymentError::InvalidPauseScope
+            );
+            server_signer.paused = paused;
+            server_signer.updated_at = Clock::get()?.unix_timestamp;
+            server_signer.updated_by = pauser;
+        }
+    }
+    Ok(())
+}
 
 // ============================================
-// Pause / Unpause (Emergency Admin Only)
+// Pause / Unpause (Emergency Admin or Pauser)
 // ============================================
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Pause<'info> {
+    /// Emergency admin or Pauser
     #[account(
-        constraint = emergency_admin.key() == config.emergency_admin @ PaymentError::UnauthorizedEmergencyAdmin
+        constraint = pauser.key() == config.emergency_admin
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::Pauser, &pauser.key())
+            @ PaymentError::UnauthorizedEmergencyAdmin
     )]
-    pub emergency_admin: Signer<'info>,
+    pub pauser: Signer<'info>,
 
     #[account(
         mut,
         seeds = [Config::SEED],
-        bump = config.bump,
-        constraint = !config.paused @ PaymentError::Paused
+        bump = config.bump
     )]
     pub config: Account<'info, Config>,
+
+    /// Required for PauseScope::Mint
+    #[account(mut)]
+    pub mint_config: Option<Account<'info, MintConfig>>,
+
+    /// Required for PauseScope::ServerSigner
+    #[account(mut)]
+    pub server_signer_account: Option<Account<'info, ServerSigner>>,
+
+    /// Pauser RoleAssignment of the signer (omit when signing as emergency admin)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
-pub fn pause_handler(ctx: Context<Pause>) -> Result<()> {
-    ctx.accounts.config.paused = true;
-    msg!("Program paused by {}", ctx.accounts.emergency_admin.key());
+pub fn pause_handler(ctx: Context<Pause>, scope: PauseScope) -> Result<()> {
+    apply_pause(
+        &mut ctx.accounts.config,
+        ctx.accounts.mint_config.as_mut(),
+        ctx.accounts.server_signer_account.as_mut(),
+        scope,
+        true,
+        ctx.accounts.pauser.key(),
+    )?;
+
+    emit_cpi!(Paused {
+        pauser: ctx.accounts.pauser.key(),
+        scope,
+    });
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Unpause<'info> {
+    /// Emergency admin or Pauser
     #[account(
-        constraint = emergency_admin.key() == config.emergency_admin @ PaymentError::UnauthorizedEmergencyAdmin
+        constraint = pauser.key() == config.emergency_admin
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::Pauser, &pauser.key())
+            @ PaymentError::UnauthorizedEmergencyAdmin
     )]
-    pub emergency_admin: Signer<'info>,
+    pub pauser: Signer<'info>,
 
     #[account(
         mut,
         seeds = [Config::SEED],
-        bump = config.bump,
-        constraint = config.paused @ PaymentError::NotPaused
+        bump = config.bump
     )]
     pub config: Account<'info, Config>,
+
+    /// Required for PauseScope::Mint
+    #[account(mut)]
+    pub mint_config: Option<Account<'info, MintConfig>>,
+
+    /// Required for PauseScope::ServerSigner
+    #[account(mut)]
+    pub server_signer_account: Option<Account<'info, ServerSigner>>,
+
+    /// Pauser RoleAssignment of the signer (omit when signing as emergency admin)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
-pub fn unpause_handler(ctx: Context<Unpause>) -> Result<()> {
-    ctx.accounts.config.paused = false;
-    msg!("Program unpaused by {}", ctx.accounts.emergency_admin.key());
+pub fn unpause_handler(ctx: Context<Unpause>, scope: PauseScope) -> Result<()> {
+    apply_pause(
+        &mut ctx.accounts.config,
+        ctx.accounts.mint_config.as_mut(),
+        ctx.accounts.server_signer_account.as_mut(),
+        scope,
+        false,
+        ctx.accounts.pauser.key(),
+    )?;
+
+    emit_cpi!(Unpaused {
+        pauser: ctx.accounts.pauser.key(),
+        scope,
+    });
     Ok(())
 }
 
 // ============================================
-// Set Emergency Admin (Authority Only)
+// Accept Emergency Admin (Pending Emergency Admin Only)
 // ============================================
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct SetEmergencyAdmin<'info> {
+pub struct AcceptEmergencyAdmin<'info> {
     #[account(
-        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+        constraint = new_emergency_admin.key() == config.pending_emergency_admin @ PaymentError::Unauthorized
     )]
-    pub authority: Signer<'info>,
+    pub new_emergency_admin: Signer<'info>,
 
     #[account(
         mut,
@@ -68,85 +177,80 @@ pub struct SetEmergencyAdmin<'info> {
         bump = config.bump
     )]
     pub config: Account<'info, Config>,
-
-    /// New emergency admin address
-    /// CHECK: Just storing the address, validated in handler
-    pub new_emergency_admin: UncheckedAccount<'info>,
 }
 
-pub fn set_emergency_admin_handler(ctx: Context<SetEmergencyAdmin>) -> Result<()> {
+pub fn accept_emergency_admin_handler(ctx: Context<AcceptEmergencyAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
     require!(
-        ctx.accounts.new_emergency_admin.key() != Pubkey::default(),
-        PaymentError::InvalidAddress
+        config.pending_emergency_admin != Pubkey::default(),
+        PaymentError::NoPendingTransfer
     );
 
-    let old_admin = ctx.accounts.config.emergency_admin;
-    ctx.accounts.config.emergency_admin = ctx.accounts.new_emergency_admin.key();
+    let old_admin = config.emergency_admin;
+    config.emergency_admin = config.pending_emergency_admin;
+    config.pending_emergency_admin = Pubkey::default();
 
-    msg!("Emergency admin changed: {} -> {}", old_admin, ctx.accounts.new_emergency_admin.key());
+    emit_cpi!(EmergencyAdminChanged {
+        old_emergency_admin: old_admin,
+        new_emergency_admin: ctx.accounts.new_emergency_admin.key(),
+    });
     Ok(())
 }
 
 // ============================================
-// Add Server Signer (Authority Only)
+// Cancel Emergency Admin Change (Authority Only)
 // ============================================
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct AddServerSigner<'info> {
+pub struct CancelEmergencyAdminChange<'info> {
     #[account(
-        mut,
         constraint = authority.key() == config.authority @ PaymentError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [Config::SEED],
         bump = config.bump
     )]
     pub config: Account<'info, Config>,
-
-    /// New server signer address
-    /// CHECK: Just storing the address, validated in handler
-    pub new_server_signer: UncheckedAccount<'info>,
-
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + ServerSigner::INIT_SPACE,
-        seeds = [ServerSigner::SEED, new_server_signer.key().as_ref()],
-        bump
-    )]
-    pub server_signer_account: Account<'info, ServerSigner>,
-
-    pub system_program: Program<'info, System>,
 }
 
-pub fn add_server_signer_handler(ctx: Context<AddServerSigner>) -> Result<()> {
+pub fn cancel_emergency_admin_change_handler(
+    ctx: Context<CancelEmergencyAdminChange>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
     require!(
-        ctx.accounts.new_server_signer.key() != Pubkey::default(),
-        PaymentError::InvalidAddress
+        config.pending_emergency_admin != Pubkey::default(),
+        PaymentError::NoPendingTransfer
     );
 
-    let server_signer = &mut ctx.accounts.server_signer_account;
-    server_signer.signer = ctx.accounts.new_server_signer.key();
-    server_signer.is_active = true;
-    server_signer.bump = ctx.bumps.server_signer_account;
+    let cancelled = config.pending_emergency_admin;
+    config.pending_emergency_admin = Pubkey::default();
 
-    msg!("Server signer added: {}", ctx.accounts.new_server_signer.key());
+    emit_cpi!(EmergencyAdminChangeCancelled {
+        authority: ctx.accounts.authority.key(),
+        cancelled_emergency_admin: cancelled,
+    });
     Ok(())
 }
 
 // ============================================
-// Remove Server Signer (Authority Only)
+// Remove Server Signer (Authority or Signer Manager)
 // ============================================
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RemoveServerSigner<'info> {
+    /// Authority or Signer Manager
     #[account(
         mut,
-        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+        constraint = signer_manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::SignerManager, &signer_manager.key())
+            @ PaymentError::Unauthorized
     )]
-    pub authority: Signer<'info>,
+    pub signer_manager: Signer<'info>,
 
     #[account(
         seeds = [Config::SEED],
@@ -162,20 +266,149 @@ pub struct RemoveServerSigner<'info> {
         mut,
         seeds = [ServerSigner::SEED, server_signer_to_remove.key().as_ref()],
         bump = server_signer_account.bump,
-        close = authority
+        close = signer_manager
     )]
     pub server_signer_account: Account<'info, ServerSigner>,
+
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED],
+        bump = signer_registry.bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+
+    /// SignerManager RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
 pub fn remove_server_signer_handler(ctx: Context<RemoveServerSigner>) -> Result<()> {
-    msg!("Server signer removed: {}", ctx.accounts.server_signer_to_remove.key());
+    ctx.accounts
+        .signer_registry
+        .remove(&ctx.accounts.server_signer_to_remove.key());
+
+    emit_cpi!(ServerSignerRemoved {
+        signer: ctx.accounts.server_signer_to_remove.key(),
+        removed_by: ctx.accounts.signer_manager.key(),
+        emergency: false,
+    });
     Ok(())
 }
 
 // ============================================
-// Emergency Add Server Signer (Emergency Admin Only)
+// Deactivate Server Signer (Authority, Emergency Admin or Signer Manager)
 // ============================================
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DeactivateServerSigner<'info> {
+    /// Authority, emergency admin or Signer Manager
+    #[account(
+        constraint = admin.key() == config.authority
+            || admin.key() == config.emergency_admin
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::SignerManager, &admin.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [ServerSigner::SEED, server_signer_account.signer.as_ref()],
+        bump = server_signer_account.bump,
+        constraint = server_signer_account.is_active @ PaymentError::InvalidServerSignerStatus
+    )]
+    pub server_signer_account: Account<'info, ServerSigner>,
+
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED],
+        bump = signer_registry.bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+
+    /// SignerManager RoleAssignment of the signer (omit when signing as authority / emergency admin)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+pub fn deactivate_server_signer_handler(ctx: Context<DeactivateServerSigner>) -> Result<()> {
+    let server_signer = &mut ctx.accounts.server_signer_account;
+    server_signer.is_active = false;
+    server_signer.updated_at = Clock::get()?.unix_timestamp;
+    server_signer.updated_by = ctx.accounts.admin.key();
+    ctx.accounts
+        .signer_registry
+        .set_active(&server_signer.signer, false);
+
+    emit_cpi!(ServerSignerDeactivated {
+        signer: server_signer.signer,
+        deactivated_by: ctx.accounts.admin.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Reactivate Server Signer (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReactivateServerSigner<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [ServerSigner::SEED, server_signer_account.signer.as_ref()],
+        bump = server_signer_account.bump,
+        constraint = !server_signer_account.is_active @ PaymentError::InvalidServerSignerStatus
+    )]
+    pub server_signer_account: Account<'info, ServerSigner>,
+
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED],
+        bump = signer_registry.bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+}
+
+pub fn reactivate_server_signer_handler(ctx: Context<ReactivateServerSigner>) -> Result<()> {
+    let server_signer = &mut ctx.accounts.server_signer_account;
+    server_signer.is_active = true;
+    server_signer.updated_at = Clock::get()?.unix_timestamp;
+    server_signer.updated_by = ctx.accounts.authority.key();
+    ctx.accounts
+        .signer_registry
+        .set_active(&server_signer.signer, true);
+
+    emit_cpi!(ServerSignerReactivated {
+        signer: server_signer.signer,
+        reactivated_by: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Emergency Add Server Signer (Emergency Admin Only, While Paused)
+// ============================================
+
+/// Pause-only emergency path: bypasses the timelock, so it is usable only while
+/// every operation is paused (PAUSE_ALL). Routine additions go through
+/// schedule_action / execute_action.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct EmergencyAddServerSigner<'info> {
     #[account(
@@ -186,7 +419,8 @@ pub struct EmergencyAddServerSigner<'info> {
 
     #[account(
         seeds = [Config::SEED],
-        bump = config.bump
+        bump = config.bump,
+        constraint = config.pause_flags & Config::PAUSE_ALL == Config::PAUSE_ALL @ PaymentError::NotPaused
     )]
     pub config: Account<'info, Config>,
 
@@ -203,6 +437,13 @@ pub struct EmergencyAddServerSigner<'info> {
     )]
     pub server_signer_account: Account<'info, ServerSigner>,
 
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED],
+        bump = signer_registry.bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -213,27 +454,38 @@ pub fn emergency_add_server_signer_handler(ctx: Context<EmergencyAddServerSigner
     );
 
     let server_signer = &mut ctx.accounts.server_signer_account;
+    server_signer.version = ServerSigner::CURRENT_VERSION;
     server_signer.signer = ctx.accounts.new_server_signer.key();
     server_signer.is_active = true;
+    server_signer.paused = false;
     server_signer.bump = ctx.bumps.server_signer_account;
-
-    msg!("EMERGENCY: Server signer added by {}: {}",
-         ctx.accounts.emergency_admin.key(),
-         ctx.accounts.new_server_signer.key());
+    server_signer.updated_at = Clock::get()?.unix_timestamp;
+    server_signer.updated_by = ctx.accounts.emergency_admin.key();
+    ctx.accounts.signer_registry.add(server_signer.signer)?;
+
+    emit_cpi!(ServerSignerAdded {
+        signer: ctx.accounts.new_server_signer.key(),
+        added_by: ctx.accounts.emergency_admin.key(),
+        emergency: true,
+    });
     Ok(())
 }
 
 // ============================================
-// Emergency Remove Server Signer (Emergency Admin Only)
+// Emergency Remove Server Signer (Emergency Admin or Signer Manager)
 // ============================================
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct EmergencyRemoveServerSigner<'info> {
+    /// Emergency admin or Signer Manager
     #[account(
         mut,
-        constraint = emergency_admin.key() == config.emergency_admin @ PaymentError::UnauthorizedEmergencyAdmin
+        constraint = signer_manager.key() == config.emergency_admin
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::SignerManager, &signer_manager.key())
+            @ PaymentError::UnauthorizedEmergencyAdmin
     )]
-    pub emergency_admin: Signer<'info>,
+    pub signer_manager: Signer<'info>,
 
     #[account(
         seeds = [Config::SEED],
@@ -249,24 +501,81 @@ pub struct EmergencyRemoveServerSigner<'info> {
         mut,
         seeds = [ServerSigner::SEED, server_signer_to_remove.key().as_ref()],
         bump = server_signer_account.bump,
-        close = emergency_admin
+        close = signer_manager
     )]
     pub server_signer_account: Account<'info, ServerSigner>,
+
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED],
+        bump = signer_registry.bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+
+    /// SignerManager RoleAssignment of the signer (omit when signing as emergency admin)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
-pub fn emergency_remove_server_signer_handler(ctx: Context<EmergencyRemoveServerSigner>) -> Result<()> {
-    msg!("EMERGENCY: Server signer removed by {}: {}",
-         ctx.accounts.emergency_admin.key(),
-         ctx.accounts.server_signer_to_remove.key());
+pub fn emergency_remove_server_signer_handler(
+    ctx: Context<EmergencyRemoveServerSigner>,
+) -> Result<()> {
+    ctx.accounts
+        .signer_registry
+        .remove(&ctx.accounts.server_signer_to_remove.key());
+
+    emit_cpi!(ServerSignerRemoved {
+        signer: ctx.accounts.server_signer_to_remove.key(),
+        removed_by: ctx.accounts.signer_manager.key(),
+        emergency: true,
+    });
     Ok(())
 }
 
 // ============================================
-// Transfer Authority
+// Accept Authority (Pending Authority Only)
 // ============================================
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct TransferAuthority<'info> {
+pub struct AcceptAuthority<'info> {
+    #[account(
+        constraint = new_authority.key() == config.pending_authority @ PaymentError::Unauthorized
+    )]
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn accept_authority_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.pending_authority != Pubkey::default(),
+        PaymentError::NoPendingTransfer
+    );
+
+    let old_authority = config.authority;
+    config.authority = config.pending_authority;
+    config.pending_authority = Pubkey::default();
+
+    emit_cpi!(AuthorityTransferred {
+        old_authority,
+        new_authority: ctx.accounts.new_authority.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Cancel Authority Transfer (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
     #[account(
         constraint = authority.key() == config.authority @ PaymentError::Unauthorized
     )]
@@ -278,22 +587,52 @@ pub struct TransferAuthority<'info> {
         bump = config.bump
     )]
     pub config: Account<'info, Config>,
-
-    /// New authority address
-    /// CHECK: Just storing the address, validated in handler
-    pub new_authority: UncheckedAccount<'info>,
 }
 
-pub fn transfer_authority_handler(ctx: Context<TransferAuthority>) -> Result<()> {
+pub fn cancel_authority_transfer_handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
     require!(
-        ctx.accounts.new_authority.key() != Pubkey::default(),
-        PaymentError::InvalidAddress
+        config.pending_authority != Pubkey::default(),
+        PaymentError::NoPendingTransfer
     );
 
-    let old_authority = ctx.accounts.config.authority;
-    ctx.accounts.config.authority = ctx.accounts.new_authority.key();
+    let cancelled = config.pending_authority;
+    config.pending_authority = Pubkey::default();
 
-    msg!("Authority transferred: {} -> {}", old_authority, ctx.accounts.new_authority.key());
+    emit_cpi!(AuthorityTransferCancelled {
+        authority: ctx.accounts.authority.key(),
+        cancelled_authority: cancelled,
+    });
     Ok(())
 }
 
+// ============================================
+// Set Memo Format (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMemoFormat<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_memo_format_handler(ctx: Context<SetMemoFormat>, memo_format: MemoFormat) -> Result<()> {
+    let old_memo_format = ctx.accounts.config.memo_format;
+    ctx.accounts.config.memo_format = memo_format;
+
+    emit_cpi!(MemoFormatChanged {
+        old_memo_format,
+        new_memo_format: memo_format,
+    });
+    Ok(())
+}
@@ -0,0 +1,371 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::errors::PaymentError;
+use crate::events::{
+    CouncilProposalApproved, CouncilProposalCancelled, CouncilProposalCreated,
+    CouncilProposalExecuted, CouncilUpdated,
+};
+use crate::program::SettoPayment;
+use crate::state::{Config, Council, CouncilProposal, ProposalAccount};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CouncilMembersParams {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CouncilProposalParams {
+    /// Accounts of the proposed instruction of this program, in order
+    pub accounts: Vec<ProposalAccount>,
+    /// Instruction data (Anchor discriminator + borsh args)
+    pub data: Vec<u8>,
+}
+
+fn validate_members(params: &CouncilMembersParams) -> Result<()> {
+    require!(
+        !params.members.is_empty() && params.members.len() <= Council::MAX_MEMBERS,
+        PaymentError::InvalidCouncil
+    );
+    require!(
+        params.threshold > 0 && params.threshold as usize <= params.members.len(),
+        PaymentError::InvalidCouncil
+    );
+    for (i, member) in params.members.iter().enumerate() {
+        require!(*member != Pubkey::default(), PaymentError::InvalidAddress);
+        require!(
+            !params.members[..i].contains(member),
+            PaymentError::InvalidCouncil
+        );
+    }
+    Ok(())
+}
+
+// ============================================
+// Create Council (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateCouncil<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Council::INIT_SPACE,
+        seeds = [Council::SEED],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    /// Council authority PDA (target for Config.authority)
+    /// CHECK: PDA derivation only, holds no data
+    #[account(
+        seeds = [Council::AUTHORITY_SEED],
+        bump
+    )]
+    pub council_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_council_handler(
+    ctx: Context<CreateCouncil>,
+    params: CouncilMembersParams,
+) -> Result<()> {
+    validate_members(&params)?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = params.members.clone();
+    council.threshold = params.threshold;
+    council.proposal_count = 0;
+    council.authority_bump = ctx.bumps.council_authority;
+    council.bump = ctx.bumps.council;
+
+    emit_cpi!(CouncilUpdated {
+        council_authority: ctx.accounts.council_authority.key(),
+        members: params.members,
+        threshold: params.threshold,
+    });
+    Ok(())
+}
+
+// ============================================
+// Set Council Members (Council Only, via proposal)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCouncilMembers<'info> {
+    #[account(
+        seeds = [Council::AUTHORITY_SEED],
+        bump = council.authority_bump
+    )]
+    pub council_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Council::SEED],
+        bump = council.bump
+    )]
+    pub council: Account<'info, Council>,
+}
+
+pub fn set_council_members_handler(
+    ctx: Context<SetCouncilMembers>,
+    params: CouncilMembersParams,
+) -> Result<()> {
+    validate_members(&params)?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = params.members.clone();
+    council.threshold = params.threshold;
+
+    emit_cpi!(CouncilUpdated {
+        council_authority: ctx.accounts.council_authority.key(),
+        members: params.members,
+        threshold: params.threshold,
+    });
+    Ok(())
+}
+
+// ============================================
+// Create Proposal (Council Member)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateCouncilProposal<'info> {
+    #[account(
+        mut,
+        constraint = council.is_member(&proposer.key()) @ PaymentError::NotCouncilMember
+    )]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Council::SEED],
+        bump = council.bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + CouncilProposal::INIT_SPACE,
+        seeds = [CouncilProposal::SEED, council.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_council_proposal_handler(
+    ctx: Context<CreateCouncilProposal>,
+    params: CouncilProposalParams,
+) -> Result<()> {
+    require!(
+        params.accounts.len() <= CouncilProposal::MAX_ACCOUNTS,
+        PaymentError::InvalidProposal
+    );
+    require!(
+        !params.data.is_empty() && params.data.len() <= CouncilProposal::MAX_DATA_LEN,
+        PaymentError::InvalidProposal
+    );
+
+    let council = &mut ctx.accounts.council;
+    let id = council.proposal_count;
+    council.proposal_count = id.checked_add(1).ok_or(PaymentError::AmountMismatch)?;
+
+    // Proposing counts as the proposer's approval
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.accounts = params.accounts;
+    proposal.data = params.data;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.bump = ctx.bumps.proposal;
+
+    emit_cpi!(CouncilProposalCreated {
+        id,
+        proposer: ctx.accounts.proposer.key(),
+        data: proposal.data.clone(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Approve Proposal (Council Member)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveCouncilProposal<'info> {
+    #[account(
+        constraint = council.is_member(&member.key()) @ PaymentError::NotCouncilMember
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [Council::SEED],
+        bump = council.bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [CouncilProposal::SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+pub fn approve_council_proposal_handler(ctx: Context<ApproveCouncilProposal>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        !proposal.approvals.contains(&member),
+        PaymentError::AlreadyApproved
+    );
+
+    // Drop approvals of removed members so the list stays within max_len
+    let council = &ctx.accounts.council;
+    proposal
+        .approvals
+        .retain(|approver| council.is_member(approver));
+    proposal.approvals.push(member);
+
+    emit_cpi!(CouncilProposalApproved {
+        id: proposal.id,
+        member,
+        approvals: proposal.approvals.len() as u8,
+    });
+    Ok(())
+}
+
+// ============================================
+// Execute Proposal (Anyone, once threshold is met)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteCouncilProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [Council::SEED],
+        bump = council.bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [CouncilProposal::SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    /// Receives the proposal's rent
+    /// CHECK: Must match proposal.proposer
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub setto_program: Program<'info, SettoPayment>,
+}
+
+/// remaining_accounts: the proposal's accounts, in order
+pub fn execute_council_proposal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteCouncilProposal<'info>>,
+) -> Result<()> {
+    let council = &ctx.accounts.council;
+    let proposal = &ctx.accounts.proposal;
+
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approver| council.is_member(approver))
+        .count();
+    require!(
+        approvals >= council.threshold as usize,
+        PaymentError::ThresholdNotMet
+    );
+
+    require!(
+        ctx.remaining_accounts.len() == proposal.accounts.len(),
+        PaymentError::InvalidProposal
+    );
+    for (info, account) in ctx.remaining_accounts.iter().zip(&proposal.accounts) {
+        require_keys_eq!(info.key(), account.pubkey, PaymentError::InvalidProposal);
+    }
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: proposal
+            .accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.pubkey,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: proposal.data.clone(),
+    };
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.setto_program.to_account_info());
+
+    let id = proposal.id;
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[Council::AUTHORITY_SEED, &[council.authority_bump]]],
+    )?;
+
+    emit_cpi!(CouncilProposalExecuted {
+        id,
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Cancel Proposal (Proposer Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelCouncilProposal<'info> {
+    #[account(mut, address = proposal.proposer @ PaymentError::Unauthorized)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CouncilProposal::SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+pub fn cancel_council_proposal_handler(ctx: Context<CancelCouncilProposal>) -> Result<()> {
+    emit_cpi!(CouncilProposalCancelled {
+        id: ctx.accounts.proposal.id,
+        proposer: ctx.accounts.proposer.key(),
+    });
+    Ok(())
+}
@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use super::utils::{build_direct_payment_message, emit_memo, verify_server_signature};
+use super::direct_payment::DirectPaymentParams;
+use super::payment_core::{
+    execute_direct_payment, DirectPaymentAccounts, SenderAuthority, TransferFeeMode,
+};
 use crate::errors::PaymentError;
-use crate::state::{Config, Delegate, ServerSigner};
+use crate::state::{
+    Config, Delegate, DirectPaymentRecord, FeeConfig, GasTank, Merchant, MintConfig, ServerSigner,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: DirectPaymentDelegatedParams)]
 pub struct ProcessDirectPaymentDelegated<'info> {
@@ -19,7 +25,7 @@ pub struct ProcessDirectPaymentDelegated<'info> {
     #[account(
         seeds = [Config::SEED],
         bump = config.bump,
-        constraint = !config.paused @ PaymentError::Paused
+        constraint = !config.is_paused(Config::PAUSE_DIRECT_DELEGATED) @ PaymentError::DirectDelegatedPaymentsPaused
     )]
     pub config: Account<'info, Config>,
 
@@ -34,13 +40,21 @@ pub struct ProcessDirectPaymentDelegated<'info> {
     #[account(
         seeds = [ServerSigner::SEED, params.server_signer.as_ref()],
         bump = server_signer_account.bump,
-        constraint = server_signer_account.is_active @ PaymentError::UnauthorizedServerSigner
+        constraint = server_signer_account.is_active @ PaymentError::UnauthorizedServerSigner,
+        constraint = !server_signer_account.paused @ PaymentError::ServerSignerPaused
     )]
     pub server_signer_account: Account<'info, ServerSigner>,
 
     /// Token mint (SPL Token or Token-2022)
     pub token_mint: InterfaceAccount<'info, Mint>,
 
+    /// Mint allowlist entry — mint must be enabled for this payment kind
+    #[account(
+        seeds = [MintConfig::SEED, token_mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
     /// Sender's token account (source)
     /// Must have delegate set to Delegate PDA with sufficient delegated_amount
     #[account(
@@ -62,12 +76,65 @@ pub struct ProcessDirectPaymentDelegated<'info> {
     pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Fee wallet's token account (receives protocol_fee)
+    /// Optional — required only when protocol_fee > 0
     #[account(
         mut,
         constraint = fee_wallet_token_account.owner == params.fee_wallet @ PaymentError::InvalidFeeRecipient,
         constraint = fee_wallet_token_account.mint == token_mint.key()
     )]
-    pub fee_wallet_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub fee_wallet_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Recipient merchant — required when Config.merchant_registry_required
+    /// recipient_token_account must be its settlement account for the mint
+    #[account(
+        seeds = [Merchant::SEED, merchant.owner.as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Option<Account<'info, Merchant>>,
+
+    /// Recipient merchant's gas tank — reimburses payer after the payment
+    /// Optional — requires the merchant account
+    #[account(
+        mut,
+        seeds = [GasTank::SEED, gas_tank.merchant.as_ref()],
+        bump = gas_tank.bump
+    )]
+    pub gas_tank: Option<Account<'info, GasTank>>,
+
+    /// Payment record (init rejects a reused payment_id — one use per server signature)
+    /// Optional — required only with gas_tank, so a signed reimbursement pays out once
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DirectPaymentRecord::INIT_SPACE,
+        seeds = [DirectPaymentRecord::SEED, params.payment_id.as_ref()],
+        bump
+    )]
+    pub payment_record: Option<Account<'info, DirectPaymentRecord>>,
+
+    /// Global fee config — fee_wallet / protocol_fee are validated against it
+    #[account(
+        seeds = [FeeConfig::SEED],
+        bump = fee_config.bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    /// Per-mint fee override (takes precedence over fee_config when initialized)
+    /// CHECK: PDA address enforced by seeds, deserialized in handler if initialized
+    #[account(
+        seeds = [FeeConfig::SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_fee_config: UncheckedAccount<'info>,
+
+    /// Relayer's token account (receives relayer_fee)
+    /// Optional — required only when relayer_fee > 0
+    #[account(
+        mut,
+        constraint = relayer_token_account.owner == params.relayer @ PaymentError::InvalidRelayer,
+        constraint = relayer_token_account.mint == token_mint.key()
+    )]
+    pub relayer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// Memo program for permanent on-chain logging
     /// CHECK: Validated by address constraint
@@ -81,6 +148,8 @@ pub struct ProcessDirectPaymentDelegated<'info> {
     /// CHECK: Validated by address constraint
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -90,102 +159,80 @@ pub struct DirectPaymentDelegatedParams {
     pub amount: u64,
     pub protocol_fee: u64,
     pub fee_wallet: Pubkey,
+    /// Relayer reimbursed with relayer_fee (covered by the server signature)
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+    /// Token-2022 transfer fee handling (covered by the server signature)
+    pub transfer_fee_mode: TransferFeeMode,
+    /// Lamports reimbursed to the payer from the merchant's gas tank (covered by the
+    /// server signature together with the payer and gas tank; 0 = no reimbursement)
+    pub gas_reimbursement: u64,
+    /// Merchant order reference (≤ 64 bytes, covered by the server signature)
+    pub reference: Vec<u8>,
+    /// Leading remaining accounts that are Solana Pay reference keys (≤ 4,
+    /// covered by the server signature); the rest are TransferHook accounts
+    pub reference_key_count: u8,
     pub deadline: i64,
     pub server_signer: Pubkey,
     pub server_signature: [u8; 64],
 }
 
-pub fn process_direct_payment_delegated_handler(
-    ctx: Context<ProcessDirectPaymentDelegated>,
+impl From<DirectPaymentDelegatedParams> for DirectPaymentParams {
+    fn from(params: DirectPaymentDelegatedParams) -> Self {
+        Self {
+            payment_id: params.payment_id,
+            total_amount: params.total_amount,
+            amount: params.amount,
+            protocol_fee: params.protocol_fee,
+            fee_wallet: params.fee_wallet,
+            relayer: params.relayer,
+            relayer_fee: params.relayer_fee,
+            transfer_fee_mode: params.transfer_fee_mode,
+            gas_reimbursement: params.gas_reimbursement,
+            reference: params.reference,
+            reference_key_count: params.reference_key_count,
+            deadline: params.deadline,
+            server_signer: params.server_signer,
+            server_signature: params.server_signature,
+        }
+    }
+}
+
+pub fn process_direct_payment_delegated_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessDirectPaymentDelegated<'info>>,
     params: DirectPaymentDelegatedParams,
 ) -> Result<()> {
-    // 1. Deadline validation
-    let clock = Clock::get()?;
-    require!(
-        clock.unix_timestamp <= params.deadline,
-        PaymentError::PaymentExpired
-    );
-
-    // 2. Amount validation (checked_add — overflow-safe)
-    let expected_total = params
-        .amount
-        .checked_add(params.protocol_fee)
-        .ok_or(PaymentError::AmountMismatch)?;
-    require!(params.total_amount == expected_total, PaymentError::AmountMismatch);
-    require!(params.amount > 0, PaymentError::InvalidAmount);
-
-    // 3. Server signature verification (Ed25519)
-    let message = build_direct_payment_message(
-        &params.payment_id,
-        &ctx.accounts.sender.key(),
-        &ctx.accounts.recipient_token_account.owner,
-        &params.fee_wallet,
-        &ctx.accounts.token_mint.key(),
-        params.total_amount,
-        params.amount,
-        params.protocol_fee,
-        params.deadline,
-    );
-    verify_server_signature(
-        &ctx.accounts.instructions_sysvar,
-        &params.server_signer,
-        &message,
+    let delegate = ctx.accounts.delegate.to_account_info();
+
+    let events = execute_direct_payment(
+        DirectPaymentAccounts {
+            payer: &ctx.accounts.payer,
+            sender: &ctx.accounts.sender,
+            authority: SenderAuthority::Delegate(&delegate, ctx.accounts.delegate.bump),
+            config: &ctx.accounts.config,
+            token_mint: &ctx.accounts.token_mint,
+            mint_config: &ctx.accounts.mint_config,
+            sender_token_account: &ctx.accounts.sender_token_account,
+            recipient_token_account: &ctx.accounts.recipient_token_account,
+            fee_wallet_token_account: ctx.accounts.fee_wallet_token_account.as_ref(),
+            relayer_token_account: ctx.accounts.relayer_token_account.as_ref(),
+            merchant: ctx.accounts.merchant.as_deref(),
+            gas_tank: ctx.accounts.gas_tank.as_ref(),
+            payment_record: ctx.accounts.payment_record.as_deref_mut(),
+            payment_record_bump: ctx.bumps.payment_record,
+            fee_config: &ctx.accounts.fee_config,
+            mint_fee_config: &ctx.accounts.mint_fee_config,
+            memo_program: &ctx.accounts.memo_program,
+            token_program: &ctx.accounts.token_program,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        &params.into(),
     )?;
 
-    // 4. Transfer amount to recipient using Delegate PDA
-    let delegate_seeds = &[Delegate::SEED, &[ctx.accounts.delegate.bump]];
-    let signer_seeds = &[&delegate_seeds[..]];
-    let decimals = ctx.accounts.token_mint.decimals;
-
-    token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.sender_token_account.to_account_info(),
-                mint: ctx.accounts.token_mint.to_account_info(),
-                to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: ctx.accounts.delegate.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        params.amount,
-        decimals,
-    )?;
-
-    // 5. Transfer protocol fee to fee wallet (skip if zero or default address)
-    if params.protocol_fee > 0 && params.fee_wallet != Pubkey::default() {
-        token_interface::transfer_checked(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.sender_token_account.to_account_info(),
-                    mint: ctx.accounts.token_mint.to_account_info(),
-                    to: ctx.accounts.fee_wallet_token_account.to_account_info(),
-                    authority: ctx.accounts.delegate.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            params.protocol_fee,
-            decimals,
-        )?;
+    if let Some(gas_tank_reimbursed) = events.gas_tank_reimbursed {
+        emit_cpi!(gas_tank_reimbursed);
     }
-
-    // 6. Emit permanent on-chain memo
-    let payment_id_hex = params
-        .payment_id
-        .iter()
-        .map(|b| format!("{:02x}", b))
-        .collect::<String>();
-    let memo = format!(
-        "DIRECT_PAYMENT|{}|{}|{}|{}|{}|{}",
-        payment_id_hex,
-        ctx.accounts.sender.key(),
-        ctx.accounts.recipient_token_account.owner,
-        params.total_amount,
-        params.amount,
-        params.protocol_fee,
-    );
-    emit_memo(&ctx.accounts.memo_program, &memo)?;
-
+    emit_cpi!(events.settled);
     Ok(())
 }
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use super::direct_payment_delegated::DirectPaymentDelegatedParams;
+use super::payment_core::{execute_direct_payment, DirectPaymentAccounts, SenderAuthority};
+use crate::errors::PaymentError;
+use crate::state::{
+    Config, Delegate, DirectPaymentRecord, FeeConfig, GasTank, Merchant, MintConfig, ServerSigner,
+};
+
+/// Delegated direct payment to wallet addresses (user doesn't sign)
+/// Recipient / fee wallet ATAs are created idempotently (paid by payer),
+/// so no separate associated-token-account instruction is needed
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: DirectPaymentDelegatedParams)]
+pub struct ProcessDirectPaymentDelegatedAta<'info> {
+    /// Payer for transaction fees and ATA rent (anyone — no relayer constraint in V2)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Token owner (NOT a signer — delegate PDA has transfer authority)
+    /// CHECK: User doesn't need to sign; delegate PDA is the authority
+    pub sender: AccountInfo<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(Config::PAUSE_DIRECT_DELEGATED) @ PaymentError::DirectDelegatedPaymentsPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Delegate PDA — has authority to transfer tokens on behalf of users
+    #[account(
+        seeds = [Delegate::SEED],
+        bump = delegate.bump,
+    )]
+    pub delegate: Box<Account<'info, Delegate>>,
+
+    /// Server signer PDA — validates the signature came from an authorized signer
+    #[account(
+        seeds = [ServerSigner::SEED, params.server_signer.as_ref()],
+        bump = server_signer_account.bump,
+        constraint = server_signer_account.is_active @ PaymentError::UnauthorizedServerSigner,
+        constraint = !server_signer_account.paused @ PaymentError::ServerSignerPaused
+    )]
+    pub server_signer_account: Box<Account<'info, ServerSigner>>,
+
+    /// Token mint (SPL Token or Token-2022)
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Mint allowlist entry — mint must be enabled for this payment kind
+    #[account(
+        seeds = [MintConfig::SEED, token_mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// Sender's token account (source)
+    /// Must have delegate set to Delegate PDA with sufficient delegated_amount
+    #[account(
+        mut,
+        constraint = sender_token_account.owner == sender.key(),
+        constraint = sender_token_account.mint == token_mint.key(),
+        constraint = sender_token_account.delegate.is_some() @ PaymentError::DelegateNotSet,
+        constraint = sender_token_account.delegate.unwrap() == delegate.key() @ PaymentError::InvalidDelegate,
+        constraint = sender_token_account.delegated_amount >= params.total_amount @ PaymentError::InsufficientDelegatedAmount
+    )]
+    pub sender_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Recipient wallet (covered by the server signature)
+    /// CHECK: Only used as the ATA authority
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Recipient's canonical ATA (receives amount, created if missing)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Fee wallet (must match params.fee_wallet)
+    /// CHECK: Validated by address constraint, only used as the ATA authority
+    #[account(address = params.fee_wallet @ PaymentError::InvalidFeeRecipient)]
+    pub fee_wallet: UncheckedAccount<'info>,
+
+    /// Fee wallet's canonical ATA (receives protocol_fee, created if missing)
+    /// Optional — required only when protocol_fee > 0
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = fee_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub fee_wallet_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Recipient merchant — required when Config.merchant_registry_required
+    /// recipient_token_account must be its settlement account for the mint
+    #[account(
+        seeds = [Merchant::SEED, merchant.owner.as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Option<Box<Account<'info, Merchant>>>,
+
+    /// Recipient merchant's gas tank — reimburses payer after the payment
+    /// Optional — requires the merchant account
+    #[account(
+        mut,
+        seeds = [GasTank::SEED, gas_tank.merchant.as_ref()],
+        bump = gas_tank.bump
+    )]
+    pub gas_tank: Option<Box<Account<'info, GasTank>>>,
+
+    /// Payment record (init rejects a reused payment_id — one use per server signature)
+    /// Optional — required only with gas_tank, so a signed reimbursement pays out once
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DirectPaymentRecord::INIT_SPACE,
+        seeds = [DirectPaymentRecord::SEED, params.payment_id.as_ref()],
+        bump
+    )]
+    pub payment_record: Option<Box<Account<'info, DirectPaymentRecord>>>,
+
+    /// Global fee config — fee_wallet / protocol_fee are validated against it
+    #[account(
+        seeds = [FeeConfig::SEED],
+        bump = fee_config.bump
+    )]
+    pub fee_config: Box<Account<'info, FeeConfig>>,
+
+    /// Per-mint fee override (takes precedence over fee_config when initialized)
+    /// CHECK: PDA address enforced by seeds, deserialized in handler if initialized
+    #[account(
+        seeds = [FeeConfig::SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_fee_config: UncheckedAccount<'info>,
+
+    /// Relayer's token account (receives relayer_fee)
+    /// Optional — required only when relayer_fee > 0
+    #[account(
+        mut,
+        constraint = relayer_token_account.owner == params.relayer @ PaymentError::InvalidRelayer,
+        constraint = relayer_token_account.mint == token_mint.key()
+    )]
+    pub relayer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Memo program for permanent on-chain logging
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_memo::ID)]
+    pub memo_program: AccountInfo<'info>,
+
+    /// Token program (SPL Token or Token-2022, auto-validated by Interface)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Instructions sysvar for Ed25519 signature verification
+    /// CHECK: Validated by address constraint
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn process_direct_payment_delegated_ata_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessDirectPaymentDelegatedAta<'info>>,
+    params: DirectPaymentDelegatedParams,
+) -> Result<()> {
+    let delegate = ctx.accounts.delegate.to_account_info();
+
+    let events = execute_direct_payment(
+        DirectPaymentAccounts {
+            payer: &ctx.accounts.payer,
+            sender: &ctx.accounts.sender,
+            authority: SenderAuthority::Delegate(&delegate, ctx.accounts.delegate.bump),
+            config: &ctx.accounts.config,
+            token_mint: &ctx.accounts.token_mint,
+            mint_config: &ctx.accounts.mint_config,
+            sender_token_account: &ctx.accounts.sender_token_account,
+            recipient_token_account: &ctx.accounts.recipient_token_account,
+            fee_wallet_token_account: ctx.accounts.fee_wallet_token_account.as_deref(),
+            relayer_token_account: ctx.accounts.relayer_token_account.as_deref(),
+            merchant: ctx.accounts.merchant.as_deref().map(|m| &**m),
+            gas_tank: ctx.accounts.gas_tank.as_deref(),
+            payment_record: ctx
+                .accounts
+                .payment_record
+                .as_deref_mut()
+                .map(|record| &mut **record),
+            payment_record_bump: ctx.bumps.payment_record,
+            fee_config: &ctx.accounts.fee_config,
+            mint_fee_config: &ctx.accounts.mint_fee_config,
+            memo_program: &ctx.accounts.memo_program,
+            token_program: &ctx.accounts.token_program,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        &params.into(),
+    )?;
+
+    if let Some(gas_tank_reimbursed) = events.gas_tank_reimbursed {
+        emit_cpi!(gas_tank_reimbursed);
+    }
+    emit_cpi!(events.settled);
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::PaymentError;
+use crate::events::{FeeConfigSet, MintFeeConfigRemoved};
+use crate::state::{Config, FeeConfig, Role, RoleAssignment};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FeeConfigParams {
+    pub fee_wallet: Pubkey,
+    pub fee_bps: u16,
+    pub min_fee: u64,
+    pub max_fee: u64,
+}
+
+fn apply_fee_config(
+    fee_config: &mut FeeConfig,
+    mint: Pubkey,
+    params: &FeeConfigParams,
+    bump: u8,
+) -> Result<()> {
+    require!(
+        params.fee_wallet != Pubkey::default(),
+        PaymentError::InvalidAddress
+    );
+    require!(
+        params.fee_bps <= FeeConfig::MAX_FEE_BPS,
+        PaymentError::InvalidFeeConfig
+    );
+    require!(
+        params.min_fee <= params.max_fee,
+        PaymentError::InvalidFeeConfig
+    );
+
+    fee_config.mint = mint;
+    fee_config.fee_wallet = params.fee_wallet;
+    fee_config.fee_bps = params.fee_bps;
+    fee_config.min_fee = params.min_fee;
+    fee_config.max_fee = params.max_fee;
+    fee_config.bump = bump;
+    Ok(())
+}
+
+// ============================================
+// Set Global Fee Config (Authority or Fee Manager)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFeeConfig<'info> {
+    #[account(
+        mut,
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::FeeManager, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        space = 8 + FeeConfig::INIT_SPACE,
+        seeds = [FeeConfig::SEED],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    /// FeeManager RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_fee_config_handler(ctx: Context<SetFeeConfig>, params: FeeConfigParams) -> Result<()> {
+    apply_fee_config(
+        &mut ctx.accounts.fee_config,
+        Pubkey::default(),
+        &params,
+        ctx.bumps.fee_config,
+    )?;
+
+    emit_cpi!(FeeConfigSet {
+        mint: Pubkey::default(),
+        fee_wallet: params.fee_wallet,
+        fee_bps: params.fee_bps,
+        min_fee: params.min_fee,
+        max_fee: params.max_fee,
+        set_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Set Per-Mint Fee Config (Authority or Fee Manager)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMintFeeConfig<'info> {
+    #[account(
+        mut,
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::FeeManager, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Token mint the override applies to
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        space = 8 + FeeConfig::INIT_SPACE,
+        seeds = [FeeConfig::SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_fee_config: Account<'info, FeeConfig>,
+
+    /// FeeManager RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_mint_fee_config_handler(
+    ctx: Context<SetMintFeeConfig>,
+    params: FeeConfigParams,
+) -> Result<()> {
+    apply_fee_config(
+        &mut ctx.accounts.mint_fee_config,
+        ctx.accounts.token_mint.key(),
+        &params,
+        ctx.bumps.mint_fee_config,
+    )?;
+
+    emit_cpi!(FeeConfigSet {
+        mint: ctx.accounts.token_mint.key(),
+        fee_wallet: params.fee_wallet,
+        fee_bps: params.fee_bps,
+        min_fee: params.min_fee,
+        max_fee: params.max_fee,
+        set_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Remove Per-Mint Fee Config (Authority or Fee Manager)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveMintFeeConfig<'info> {
+    #[account(
+        mut,
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::FeeManager, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [FeeConfig::SEED, mint_fee_config.mint.as_ref()],
+        bump = mint_fee_config.bump,
+        close = manager
+    )]
+    pub mint_fee_config: Account<'info, FeeConfig>,
+
+    /// FeeManager RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+pub fn remove_mint_fee_config_handler(ctx: Context<RemoveMintFeeConfig>) -> Result<()> {
+    emit_cpi!(MintFeeConfigRemoved {
+        mint: ctx.accounts.mint_fee_config.mint,
+        removed_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
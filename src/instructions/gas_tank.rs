@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use super::utils::available_lamports;
+use crate::errors::PaymentError;
+use crate::events::{
+    GasTankCreated, GasTankDeposited, GasTankReimbursementChanged, GasTankWithdrawn,
+};
+use crate::state::{GasTank, Merchant};
+
+// ============================================
+// Create Gas Tank (Merchant Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateGasTank<'info> {
+    #[account(mut)]
+    pub merchant_owner: Signer<'info>,
+
+    /// Registered merchant owned by the signer
+    #[account(
+        seeds = [Merchant::SEED, merchant_owner.key().as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    #[account(
+        init,
+        payer = merchant_owner,
+        space = 8 + GasTank::INIT_SPACE,
+        seeds = [GasTank::SEED, merchant_owner.key().as_ref()],
+        bump
+    )]
+    pub gas_tank: Account<'info, GasTank>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_gas_tank_handler(
+    ctx: Context<CreateGasTank>,
+    reimbursement_per_payment: u64,
+) -> Result<()> {
+    require!(
+        reimbursement_per_payment <= GasTank::MAX_REIMBURSEMENT_PER_PAYMENT,
+        PaymentError::InvalidAmount
+    );
+
+    let gas_tank = &mut ctx.accounts.gas_tank;
+    gas_tank.merchant = ctx.accounts.merchant_owner.key();
+    gas_tank.reimbursement_per_payment = reimbursement_per_payment;
+    gas_tank.bump = ctx.bumps.gas_tank;
+
+    emit_cpi!(GasTankCreated {
+        merchant: gas_tank.merchant,
+        reimbursement_per_payment,
+    });
+    Ok(())
+}
+
+// ============================================
+// Deposit Gas Tank (Anyone)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositGasTank<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GasTank::SEED, gas_tank.merchant.as_ref()],
+        bump = gas_tank.bump
+    )]
+    pub gas_tank: Account<'info, GasTank>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_gas_tank_handler(ctx: Context<DepositGasTank>, amount: u64) -> Result<()> {
+    require!(amount > 0, PaymentError::InvalidAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.gas_tank.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(GasTankDeposited {
+        merchant: ctx.accounts.gas_tank.merchant,
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        balance: available_lamports(&ctx.accounts.gas_tank.to_account_info())?,
+    });
+    Ok(())
+}
+
+// ============================================
+// Withdraw Gas Tank (Merchant Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawGasTank<'info> {
+    #[account(
+        mut,
+        constraint = merchant_owner.key() == gas_tank.merchant @ PaymentError::Unauthorized
+    )]
+    pub merchant_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GasTank::SEED, gas_tank.merchant.as_ref()],
+        bump = gas_tank.bump
+    )]
+    pub gas_tank: Account<'info, GasTank>,
+}
+
+pub fn withdraw_gas_tank_handler(ctx: Context<WithdrawGasTank>, amount: u64) -> Result<()> {
+    require!(amount > 0, PaymentError::InvalidAmount);
+
+    // Rent-exempt minimum stays in the tank
+    let gas_tank = ctx.accounts.gas_tank.to_account_info();
+    let available = available_lamports(&gas_tank)?;
+    require!(
+        available >= amount,
+        PaymentError::InsufficientGasTankBalance
+    );
+
+    **gas_tank.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.merchant_owner.try_borrow_mut_lamports()? += amount;
+
+    emit_cpi!(GasTankWithdrawn {
+        merchant: ctx.accounts.gas_tank.merchant,
+        destination: ctx.accounts.merchant_owner.key(),
+        amount,
+        balance: available - amount,
+    });
+    Ok(())
+}
+
+// ============================================
+// Set Gas Tank Reimbursement (Merchant Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGasTankReimbursement<'info> {
+    #[account(
+        constraint = merchant_owner.key() == gas_tank.merchant @ PaymentError::Unauthorized
+    )]
+    pub merchant_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GasTank::SEED, gas_tank.merchant.as_ref()],
+        bump = gas_tank.bump
+    )]
+    pub gas_tank: Account<'info, GasTank>,
+}
+
+pub fn set_gas_tank_reimbursement_handler(
+    ctx: Context<SetGasTankReimbursement>,
+    reimbursement_per_payment: u64,
+) -> Result<()> {
+    require!(
+        reimbursement_per_payment <= GasTank::MAX_REIMBURSEMENT_PER_PAYMENT,
+        PaymentError::InvalidAmount
+    );
+
+    let gas_tank = &mut ctx.accounts.gas_tank;
+    let old_reimbursement_per_payment = gas_tank.reimbursement_per_payment;
+    gas_tank.reimbursement_per_payment = reimbursement_per_payment;
+
+    emit_cpi!(GasTankReimbursementChanged {
+        merchant: gas_tank.merchant,
+        old_reimbursement_per_payment,
+        new_reimbursement_per_payment: reimbursement_per_payment,
+    });
+    Ok(())
+}
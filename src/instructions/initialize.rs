@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::PaymentError;
-use crate::state::{Config, Delegate, ServerSigner};
+use crate::events::Initialized;
+use crate::state::{Config, Delegate, MemoFormat, ServerSigner, SignerEntry, SignerRegistry};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Initialize<'info> {
     #[account(mut)]
@@ -35,6 +37,16 @@ pub struct Initialize<'info> {
     )]
     pub server_signer_account: Account<'info, ServerSigner>,
 
+    /// Enumerable list of server signers (starts with the initial signer)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SignerRegistry::INIT_SPACE,
+        seeds = [SignerRegistry::SEED],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+
     /// Delegate PDA for gasless token transfers
     #[account(
         init,
@@ -59,24 +71,44 @@ pub fn initialize_handler(ctx: Context<Initialize>) -> Result<()> {
     );
 
     let config = &mut ctx.accounts.config;
+    config.version = Config::CURRENT_VERSION;
     config.authority = ctx.accounts.authority.key();
     config.emergency_admin = ctx.accounts.emergency_admin.key();
-    config.paused = false;
+    config.pending_authority = Pubkey::default();
+    config.pending_emergency_admin = Pubkey::default();
+    config.pause_flags = 0;
+    config.merchant_registry_required = false;
+    config.memo_format = MemoFormat::Text;
+    config.timelock_delay = Config::DEFAULT_TIMELOCK_DELAY;
+    config.action_count = 0;
     config.bump = ctx.bumps.config;
 
     let server_signer = &mut ctx.accounts.server_signer_account;
+    server_signer.version = ServerSigner::CURRENT_VERSION;
     server_signer.signer = ctx.accounts.server_signer.key();
     server_signer.is_active = true;
+    server_signer.paused = false;
     server_signer.bump = ctx.bumps.server_signer_account;
+    server_signer.updated_at = Clock::get()?.unix_timestamp;
+    server_signer.updated_by = ctx.accounts.authority.key();
+
+    let signer_registry = &mut ctx.accounts.signer_registry;
+    signer_registry.signers = vec![SignerEntry {
+        signer: server_signer.signer,
+        is_active: true,
+    }];
+    signer_registry.max_signers = SignerRegistry::DEFAULT_MAX_SIGNERS;
+    signer_registry.bump = ctx.bumps.signer_registry;
 
     let delegate = &mut ctx.accounts.delegate;
+    delegate.version = Delegate::CURRENT_VERSION;
     delegate.bump = ctx.bumps.delegate;
 
-    msg!("Config initialized");
-    msg!("Authority: {}", config.authority);
-    msg!("Emergency admin: {}", config.emergency_admin);
-    msg!("Initial server signer: {}", server_signer.signer);
-    msg!("Delegate PDA: {}", ctx.accounts.delegate.key());
-
+    emit_cpi!(Initialized {
+        authority: config.authority,
+        emergency_admin: config.emergency_admin,
+        server_signer: server_signer.signer,
+        delegate: ctx.accounts.delegate.key(),
+    });
     Ok(())
 }
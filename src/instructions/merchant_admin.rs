@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::PaymentError;
+use crate::events::{
+    MerchantRegistered, MerchantRegistryRequiredChanged, MerchantSettlementAccountRemoved,
+    MerchantSettlementAccountSet, MerchantStatusChanged, MerchantUpdated,
+};
+use crate::state::{Config, Merchant, MerchantStatus, Role, RoleAssignment, SettlementAccount};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MerchantParams {
+    pub fee_tier: u8,
+    pub name: String,
+    pub uri: String,
+}
+
+fn apply_merchant_params(merchant: &mut Merchant, params: MerchantParams) -> Result<()> {
+    require!(
+        params.name.len() <= Merchant::MAX_NAME_LEN,
+        PaymentError::InvalidMerchantMetadata
+    );
+    require!(
+        params.uri.len() <= Merchant::MAX_URI_LEN,
+        PaymentError::InvalidMerchantMetadata
+    );
+
+    merchant.fee_tier = params.fee_tier;
+    merchant.name = params.name;
+    merchant.uri = params.uri;
+    Ok(())
+}
+
+// ============================================
+// Register Merchant (Authority or Merchant Manager)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterMerchant<'info> {
+    #[account(
+        mut,
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::MerchantManager, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Merchant wallet
+    /// CHECK: Just storing the address, validated in handler
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + Merchant::INIT_SPACE,
+        seeds = [Merchant::SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    /// MerchantManager RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_merchant_handler(
+    ctx: Context<RegisterMerchant>,
+    params: MerchantParams,
+) -> Result<()> {
+    require!(
+        ctx.accounts.owner.key() != Pubkey::default(),
+        PaymentError::InvalidAddress
+    );
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.owner = ctx.accounts.owner.key();
+    merchant.settlement_accounts = Vec::new();
+    merchant.status = MerchantStatus::Active;
+    merchant.bump = ctx.bumps.merchant;
+    apply_merchant_params(merchant, params)?;
+
+    emit_cpi!(MerchantRegistered {
+        merchant: merchant.owner,
+        fee_tier: merchant.fee_tier,
+        name: merchant.name.clone(),
+        uri: merchant.uri.clone(),
+        registered_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Update Merchant (Authority or Merchant Manager)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMerchant<'info> {
+    #[account(
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::MerchantManager, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Merchant::SEED, merchant.owner.as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    /// MerchantManager RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+pub fn update_merchant_handler(ctx: Context<UpdateMerchant>, params: MerchantParams) -> Result<()> {
+    let merchant = &mut ctx.accounts.merchant;
+    apply_merchant_params(merchant, params)?;
+
+    emit_cpi!(MerchantUpdated {
+        merchant: merchant.owner,
+        fee_tier: merchant.fee_tier,
+        name: merchant.name.clone(),
+        uri: merchant.uri.clone(),
+        updated_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+pub fn set_merchant_status_handler(
+    ctx: Context<UpdateMerchant>,
+    status: MerchantStatus,
+) -> Result<()> {
+    ctx.accounts.merchant.status = status;
+
+    emit_cpi!(MerchantStatusChanged {
+        merchant: ctx.accounts.merchant.owner,
+        status,
+        changed_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+pub fn remove_merchant_settlement_account_handler(
+    ctx: Context<UpdateMerchant>,
+    mint: Pubkey,
+) -> Result<()> {
+    let merchant = &mut ctx.accounts.merchant;
+    let before = merchant.settlement_accounts.len();
+    merchant.settlement_accounts.retain(|s| s.mint != mint);
+    require!(
+        merchant.settlement_accounts.len() < before,
+        PaymentError::InvalidSettlementAccount
+    );
+
+    emit_cpi!(MerchantSettlementAccountRemoved {
+        merchant: merchant.owner,
+        mint,
+        removed_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Set Merchant Settlement Account (Authority or Merchant Manager)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMerchantSettlementAccount<'info> {
+    #[account(
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::MerchantManager, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Merchant::SEED, merchant.owner.as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    /// Mint the settlement account is registered for
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Settlement token account (replaces any existing entry for its mint)
+    /// Must be owned by the merchant wallet
+    #[account(
+        constraint = settlement_token_account.owner == merchant.owner @ PaymentError::InvalidSettlementAccount,
+        constraint = settlement_token_account.mint == token_mint.key() @ PaymentError::InvalidSettlementAccount
+    )]
+    pub settlement_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// MerchantManager RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+pub fn set_merchant_settlement_account_handler(
+    ctx: Context<SetMerchantSettlementAccount>,
+) -> Result<()> {
+    let mint = ctx.accounts.token_mint.key();
+    let token_account = ctx.accounts.settlement_token_account.key();
+    let merchant = &mut ctx.accounts.merchant;
+
+    match merchant
+        .settlement_accounts
+        .iter_mut()
+        .find(|s| s.mint == mint)
+    {
+        Some(existing) => existing.token_account = token_account,
+        None => {
+            require!(
+                merchant.settlement_accounts.len() < Merchant::MAX_SETTLEMENT_ACCOUNTS,
+                PaymentError::TooManySettlementAccounts
+            );
+            merchant.settlement_accounts.push(SettlementAccount {
+                mint,
+                token_account,
+            });
+        }
+    }
+
+    emit_cpi!(MerchantSettlementAccountSet {
+        merchant: merchant.owner,
+        mint,
+        token_account,
+        set_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Merchant Registry Settings (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMerchantRegistryConfig<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_merchant_registry_required_handler(
+    ctx: Context<SetMerchantRegistryConfig>,
+    required: bool,
+) -> Result<()> {
+    ctx.accounts.config.merchant_registry_required = required;
+    emit_cpi!(MerchantRegistryRequiredChanged {
+        required,
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
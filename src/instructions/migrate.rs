@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::PaymentError;
+use crate::events::AccountMigrated;
+use crate::state::{
+    Config, Delegate, LegacyConfig, LegacyDelegate, LegacyServerSigner, MemoFormat, ServerSigner,
+};
+
+/// Check the discriminator and return the account data length
+fn checked_len(account: &AccountInfo, discriminator: &[u8]) -> Result<usize> {
+    let data = account.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == *discriminator,
+        PaymentError::InvalidAccountLayout
+    );
+    Ok(data.len())
+}
+
+/// Top up rent from `payer`, resize to `space` and write `value` (discriminator included)
+fn write_migrated<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    value: &T,
+    space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let shortfall = rent.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    account.resize(space)?;
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    value.try_serialize(&mut writer)
+}
+
+// ============================================
+// Migrate Config (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Legacy layouts don't deserialize as Config; parsed in handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_config_handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+    let len = checked_len(&info, Config::DISCRIMINATOR)?;
+
+    // Layouts are told apart by length; only version 0 predates the version field
+    require!(len == LegacyConfig::LEN, PaymentError::AlreadyMigrated);
+    let legacy = LegacyConfig::deserialize(&mut &info.try_borrow_data()?[8..])?;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        legacy.authority,
+        PaymentError::Unauthorized
+    );
+
+    let config = Config {
+        version: Config::CURRENT_VERSION,
+        authority: legacy.authority,
+        emergency_admin: legacy.emergency_admin,
+        pending_authority: Pubkey::default(),
+        pending_emergency_admin: Pubkey::default(),
+        pause_flags: if legacy.paused { Config::PAUSE_ALL } else { 0 },
+        merchant_registry_required: false,
+        memo_format: MemoFormat::Text,
+        timelock_delay: Config::DEFAULT_TIMELOCK_DELAY,
+        action_count: 0,
+        bump: legacy.bump,
+        reserved: [0; 64],
+    };
+    write_migrated(
+        &info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &config,
+        8 + Config::INIT_SPACE,
+    )?;
+
+    emit_cpi!(AccountMigrated {
+        account: info.key(),
+        from_version: 0,
+        to_version: Config::CURRENT_VERSION,
+    });
+    Ok(())
+}
+
+// ============================================
+// Migrate Server Signer (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateServerSigner<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Must already be migrated
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Server signer whose PDA is migrated
+    /// CHECK: Used for PDA derivation
+    pub server_signer: UncheckedAccount<'info>,
+
+    /// CHECK: Legacy layouts don't deserialize as ServerSigner; parsed in handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [ServerSigner::SEED, server_signer.key().as_ref()],
+        bump
+    )]
+    pub server_signer_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_server_signer_handler(ctx: Context<MigrateServerSigner>) -> Result<()> {
+    let info = ctx.accounts.server_signer_account.to_account_info();
+    let len = checked_len(&info, ServerSigner::DISCRIMINATOR)?;
+
+    require!(
+        len == LegacyServerSigner::LEN,
+        PaymentError::AlreadyMigrated
+    );
+    let legacy = LegacyServerSigner::deserialize(&mut &info.try_borrow_data()?[8..])?;
+
+    let server_signer = ServerSigner {
+        version: ServerSigner::CURRENT_VERSION,
+        signer: legacy.signer,
+        is_active: legacy.is_active,
+        paused: false,
+        bump: legacy.bump,
+        updated_at: 0,
+        updated_by: Pubkey::default(),
+        reserved: [0; 24],
+    };
+    write_migrated(
+        &info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &server_signer,
+        8 + ServerSigner::INIT_SPACE,
+    )?;
+
+    emit_cpi!(AccountMigrated {
+        account: info.key(),
+        from_version: 0,
+        to_version: ServerSigner::CURRENT_VERSION,
+    });
+    Ok(())
+}
+
+// ============================================
+// Migrate Delegate (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateDelegate<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Must already be migrated
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Legacy layouts don't deserialize as Delegate; parsed in handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [Delegate::SEED],
+        bump
+    )]
+    pub delegate: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_delegate_handler(ctx: Context<MigrateDelegate>) -> Result<()> {
+    let info = ctx.accounts.delegate.to_account_info();
+    let len = checked_len(&info, Delegate::DISCRIMINATOR)?;
+
+    require!(len == LegacyDelegate::LEN, PaymentError::AlreadyMigrated);
+    let legacy = LegacyDelegate::deserialize(&mut &info.try_borrow_data()?[8..])?;
+
+    let delegate = Delegate {
+        version: Delegate::CURRENT_VERSION,
+        bump: legacy.bump,
+        reserved: [0; 32],
+    };
+    write_migrated(
+        &info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &delegate,
+        8 + Delegate::INIT_SPACE,
+    )?;
+
+    emit_cpi!(AccountMigrated {
+        account: info.key(),
+        from_version: 0,
+        to_version: Delegate::CURRENT_VERSION,
+    });
+    Ok(())
+}
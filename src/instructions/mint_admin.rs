@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::PaymentError;
+use crate::events::{MintConfigRemoved, MintConfigSet};
+use crate::state::{Config, MintConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintConfigParams {
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    /// Bitmask of MintConfig::KIND_DIRECT / MintConfig::KIND_POOL
+    pub allowed_kinds: u8,
+    pub allow_delegated: bool,
+}
+
+// ============================================
+// Set Mint Config (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMintConfig<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Token mint being onboarded or updated
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintConfig::INIT_SPACE,
+        seeds = [MintConfig::SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_mint_config_handler(
+    ctx: Context<SetMintConfig>,
+    params: MintConfigParams,
+) -> Result<()> {
+    require!(
+        params.min_amount <= params.max_amount,
+        PaymentError::InvalidMintConfig
+    );
+    require!(
+        params.allowed_kinds & !MintConfig::ALL_KINDS == 0,
+        PaymentError::InvalidMintConfig
+    );
+
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.mint = ctx.accounts.token_mint.key();
+    // `paused` is left to pause / unpause
+    mint_config.enabled = params.enabled;
+    mint_config.min_amount = params.min_amount;
+    mint_config.max_amount = params.max_amount;
+    mint_config.allowed_kinds = params.allowed_kinds;
+    mint_config.allow_delegated = params.allow_delegated;
+    mint_config.bump = ctx.bumps.mint_config;
+
+    emit_cpi!(MintConfigSet {
+        mint: mint_config.mint,
+        enabled: params.enabled,
+        min_amount: params.min_amount,
+        max_amount: params.max_amount,
+        allowed_kinds: params.allowed_kinds,
+        allow_delegated: params.allow_delegated,
+    });
+    Ok(())
+}
+
+// ============================================
+// Remove Mint Config (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveMintConfig<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [MintConfig::SEED, mint_config.mint.as_ref()],
+        bump = mint_config.bump,
+        close = authority
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+}
+
+pub fn remove_mint_config_handler(ctx: Context<RemoveMintConfig>) -> Result<()> {
+    emit_cpi!(MintConfigRemoved {
+        mint: ctx.accounts.mint_config.mint,
+    });
+    Ok(())
+}
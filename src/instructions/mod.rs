@@ -1,14 +1,44 @@
 pub mod admin;
+pub mod council;
 pub mod direct_payment;
+pub mod direct_payment_ata;
 pub mod direct_payment_delegated;
+pub mod direct_payment_delegated_ata;
+pub mod fee_admin;
+pub mod gas_tank;
 pub mod initialize;
+pub mod merchant_admin;
+pub mod migrate;
+pub mod mint_admin;
+mod payment_core;
+pub mod pool_admin;
 pub mod pool_payment;
 pub mod pool_payment_delegated;
+pub mod pool_refund;
+pub mod pool_settlement;
+pub mod role_admin;
+pub mod signer_registry;
+pub mod timelock;
 mod utils;
 
 pub use admin::*;
+pub use council::*;
 pub use direct_payment::*;
+pub use direct_payment_ata::*;
 pub use direct_payment_delegated::*;
+pub use direct_payment_delegated_ata::*;
+pub use fee_admin::*;
+pub use gas_tank::*;
 pub use initialize::*;
+pub use merchant_admin::*;
+pub use migrate::*;
+pub use mint_admin::*;
+pub use payment_core::TransferFeeMode;
+pub use pool_admin::*;
 pub use pool_payment::*;
 pub use pool_payment_delegated::*;
+pub use pool_refund::*;
+pub use pool_settlement::*;
+pub use role_admin::*;
+pub use signer_registry::*;
+pub use timelock::*;
@@ -0,0 +1,668 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    onchain,
+};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use super::direct_payment::DirectPaymentParams;
+use super::pool_payment::PoolPaymentParams;
+use super::utils::{
+    build_direct_payment_message, build_pool_payment_message, emit_memo, reimburse_from_gas_tank,
+    split_reference_keys, validate_merchant, validate_mint_config, validate_pool_recipient,
+    validate_protocol_fee, verify_server_signature, DirectPaymentMessage, PoolPaymentMessage,
+    MAX_REFERENCE_LEN,
+};
+use crate::errors::PaymentError;
+use crate::events::{DirectPaymentSettled, GasTankReimbursed, PaymentKind, PoolPaymentSettled};
+use crate::memo::{DirectPaymentMemo, PoolPaymentMemo, SettoMemo};
+use crate::state::{
+    Config, Delegate, DirectPaymentRecord, FeeConfig, GasTank, Merchant, MintConfig, Pool,
+    PoolLedger, PoolPaymentRecord, PoolPaymentStatus,
+};
+
+/// Authority moving tokens out of the source token account
+#[derive(Clone, Copy)]
+pub enum SenderAuthority<'a, 'info> {
+    /// Sender signs the transaction
+    Sender(&'a AccountInfo<'info>),
+    /// Delegate PDA signs via seeds (sender approved it beforehand)
+    Delegate(&'a AccountInfo<'info>, u8),
+    /// Pool PDA signs via its seeds (transfers out of the pool vault)
+    Pool(&'a AccountInfo<'info>, &'a [&'a [u8]]),
+}
+
+/// How a Token-2022 TransferFee extension is applied to each payment leg
+/// (ignored for mints without the extension)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferFeeMode {
+    /// Transfer the signed amount; destinations receive it minus the withheld fee
+    Net,
+    /// Gross up so destinations receive exactly the signed amount (the source pays the fee)
+    GrossUp,
+}
+
+/// Token-2022 TransferFeeConfig of the mint (None for SPL Token or mints without the extension)
+pub fn mint_transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if mint.owner != &spl_token_2022::ID {
+        return Ok(None);
+    }
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Moves tokens out of the sender's token account (or a pool vault), one leg at a time
+pub struct SenderTransfer<'a, 'info> {
+    pub token_program: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: SenderAuthority<'a, 'info>,
+    pub decimals: u8,
+    /// Mint's TransferFee extension — legs use transfer_checked_with_fee when set
+    pub transfer_fee: Option<TransferFeeConfig>,
+    pub transfer_fee_mode: TransferFeeMode,
+    pub epoch: u64,
+    /// Remaining accounts forwarded to Token-2022 TransferHook programs
+    pub transfer_hook_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'info> SenderTransfer<'_, 'info> {
+    /// Amount debited from the source and fee withheld by the mint for a leg
+    /// crediting `amount` per the transfer fee mode
+    pub fn fee_for(&self, amount: u64) -> Result<(u64, u64)> {
+        let Some(transfer_fee) = &self.transfer_fee else {
+            return Ok((amount, 0));
+        };
+
+        let gross = match self.transfer_fee_mode {
+            TransferFeeMode::Net => amount,
+            TransferFeeMode::GrossUp => transfer_fee
+                .calculate_inverse_epoch_fee(self.epoch, amount)
+                .and_then(|fee| amount.checked_add(fee))
+                .ok_or(PaymentError::AmountMismatch)?,
+        };
+        let withheld = transfer_fee
+            .calculate_epoch_fee(self.epoch, gross)
+            .ok_or(PaymentError::AmountMismatch)?;
+        Ok((gross, withheld))
+    }
+
+    /// Transfer one leg so that `to` is credited per the transfer fee mode.
+    /// Returns the amount withheld by the mint.
+    pub fn transfer(&self, to: &AccountInfo<'info>, amount: u64) -> Result<u64> {
+        let (gross, withheld) = self.fee_for(amount)?;
+        let fee = self.transfer_fee.map(|_| withheld);
+
+        match self.authority {
+            SenderAuthority::Sender(sender) => self.invoke(sender, to, gross, fee, &[])?,
+            SenderAuthority::Delegate(delegate, bump) => {
+                self.invoke(delegate, to, gross, fee, &[&[Delegate::SEED, &[bump]]])?
+            }
+            SenderAuthority::Pool(pool, seeds) => self.invoke(pool, to, gross, fee, &[seeds])?,
+        }
+        Ok(withheld)
+    }
+
+    fn invoke(
+        &self,
+        authority: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+        fee: Option<u64>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        transfer_checked_with_hooks(
+            &self.token_program,
+            TransferChecked {
+                from: self.from.clone(),
+                mint: self.mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            self.transfer_hook_accounts,
+            amount,
+            self.decimals,
+            fee,
+            signer_seeds,
+        )
+    }
+}
+
+/// transfer_checked (or transfer_checked_with_fee when `fee` is set) that forwards
+/// Token-2022 TransferHook accounts.
+///
+/// For Token-2022 mints the hook program's extra accounts are resolved from the
+/// mint's extra-account-meta list, looked up in `transfer_hook_accounts` (the
+/// instruction's remaining accounts, after any Solana Pay reference keys), and
+/// appended to the CPI.
+pub fn transfer_checked_with_hooks<'info>(
+    token_program: &AccountInfo<'info>,
+    accounts: TransferChecked<'info>,
+    transfer_hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    fee: Option<u64>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if token_program.key != &spl_token_2022::ID {
+        return token_interface::transfer_checked(
+            CpiContext::new_with_signer(token_program.clone(), accounts, signer_seeds),
+            amount,
+            decimals,
+        );
+    }
+
+    match fee {
+        Some(fee) => onchain::invoke_transfer_checked_with_fee(
+            token_program.key,
+            accounts.from,
+            accounts.mint,
+            accounts.to,
+            accounts.authority,
+            transfer_hook_accounts,
+            amount,
+            decimals,
+            fee,
+            signer_seeds,
+        )?,
+        None => onchain::invoke_transfer_checked(
+            token_program.key,
+            accounts.from,
+            accounts.mint,
+            accounts.to,
+            accounts.authority,
+            transfer_hook_accounts,
+            amount,
+            decimals,
+            signer_seeds,
+        )?,
+    }
+    Ok(())
+}
+
+/// Accounts shared by every direct payment variant
+/// (sender- or delegate-signed, existing token accounts or idempotent ATAs)
+pub struct DirectPaymentAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub sender: &'a AccountInfo<'info>,
+    pub authority: SenderAuthority<'a, 'info>,
+    pub config: &'a Config,
+    pub token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub mint_config: &'a MintConfig,
+    pub sender_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub recipient_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub fee_wallet_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub relayer_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub merchant: Option<&'a Merchant>,
+    pub gas_tank: Option<&'a Account<'info, GasTank>>,
+    /// Freshly initialized record for params.payment_id (replay protection),
+    /// required with gas_tank
+    pub payment_record: Option<&'a mut DirectPaymentRecord>,
+    pub payment_record_bump: Option<u8>,
+    pub fee_config: &'a FeeConfig,
+    pub mint_fee_config: &'a AccountInfo<'info>,
+    pub memo_program: &'a AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub instructions_sysvar: &'a AccountInfo<'info>,
+    /// Solana Pay reference keys followed by Token-2022 TransferHook extra accounts
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Events produced by a direct payment, emitted by the handler via emit_cpi!
+pub struct DirectPaymentEvents {
+    pub settled: DirectPaymentSettled,
+    pub gas_tank_reimbursed: Option<GasTankReimbursed>,
+}
+
+/// Validate, verify, settle and record a direct payment.
+///
+/// sender → recipient (amount) + sender → feeWallet (protocolFee)
+///   + sender → relayer (relayerFee) + merchant gas tank → payer (lamports)
+pub fn execute_direct_payment(
+    accounts: DirectPaymentAccounts,
+    params: &DirectPaymentParams,
+) -> Result<DirectPaymentEvents> {
+    // 1. Deadline validation
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= params.deadline,
+        PaymentError::PaymentExpired
+    );
+
+    // 2. Amount validation (checked_add — overflow-safe)
+    let expected_total = params
+        .amount
+        .checked_add(params.protocol_fee)
+        .and_then(|total| total.checked_add(params.relayer_fee))
+        .ok_or(PaymentError::AmountMismatch)?;
+    require!(
+        params.total_amount == expected_total,
+        PaymentError::AmountMismatch
+    );
+    require!(params.amount > 0, PaymentError::InvalidAmount);
+    require!(
+        params.reference.len() <= MAX_REFERENCE_LEN,
+        PaymentError::InvalidReference
+    );
+    let (reference_keys, transfer_hook_accounts) =
+        split_reference_keys(accounts.remaining_accounts, params.reference_key_count)?;
+
+    // 3. Mint allowlist validation (enabled, kind, delegated pulls, amount limits)
+    validate_mint_config(
+        accounts.mint_config,
+        MintConfig::KIND_DIRECT,
+        matches!(accounts.authority, SenderAuthority::Delegate(..)),
+        params.amount,
+    )?;
+
+    // 4. Fee validation against on-chain FeeConfig (global or per-mint override)
+    validate_protocol_fee(
+        accounts.fee_config,
+        accounts.mint_fee_config,
+        &params.fee_wallet,
+        params.amount,
+        params.protocol_fee,
+    )?;
+
+    // 5. Merchant registry validation
+    validate_merchant(
+        accounts.config,
+        accounts.merchant,
+        &accounts.token_mint.key(),
+        &accounts.recipient_token_account.key(),
+    )?;
+
+    // 6. Server signature verification (Ed25519)
+    let message = build_direct_payment_message(&DirectPaymentMessage {
+        payment_id: &params.payment_id,
+        sender: accounts.sender.key,
+        recipient: &accounts.recipient_token_account.owner,
+        fee_wallet: &params.fee_wallet,
+        token_mint: &accounts.token_mint.key(),
+        total_amount: params.total_amount,
+        amount: params.amount,
+        protocol_fee: params.protocol_fee,
+        relayer: &params.relayer,
+        relayer_fee: params.relayer_fee,
+        transfer_fee_mode: params.transfer_fee_mode,
+        payer: accounts.payer.key,
+        gas_tank: &accounts
+            .gas_tank
+            .map(|gas_tank| gas_tank.key())
+            .unwrap_or_default(),
+        gas_reimbursement: params.gas_reimbursement,
+        deadline: params.deadline,
+        reference: &params.reference,
+        reference_keys: &reference_keys,
+    });
+    verify_server_signature(
+        accounts.instructions_sysvar,
+        &params.server_signer,
+        &message,
+    )?;
+
+    // 7. Transfer amount to recipient (Token-2022 TransferFee applied per the signed mode)
+    let sender_transfer = SenderTransfer {
+        token_program: accounts.token_program.to_account_info(),
+        from: accounts.sender_token_account.to_account_info(),
+        mint: accounts.token_mint.to_account_info(),
+        authority: accounts.authority,
+        decimals: accounts.token_mint.decimals,
+        transfer_fee: mint_transfer_fee_config(&accounts.token_mint.to_account_info())?,
+        transfer_fee_mode: params.transfer_fee_mode,
+        epoch: clock.epoch,
+        transfer_hook_accounts,
+    };
+    let mut withheld = sender_transfer.transfer(
+        &accounts.recipient_token_account.to_account_info(),
+        params.amount,
+    )?;
+
+    // 8. Transfer protocol fee to fee wallet (a non-zero fee is always transferred)
+    if params.protocol_fee > 0 {
+        let fee_wallet_token_account = accounts
+            .fee_wallet_token_account
+            .ok_or(PaymentError::FeeAccountRequired)?;
+
+        let fee_withheld = sender_transfer.transfer(
+            &fee_wallet_token_account.to_account_info(),
+            params.protocol_fee,
+        )?;
+        withheld = withheld.saturating_add(fee_withheld);
+    }
+
+    // 9. Reimburse relayer in the payment token
+    if params.relayer_fee > 0 {
+        let relayer_token_account = accounts
+            .relayer_token_account
+            .ok_or(PaymentError::RelayerAccountRequired)?;
+
+        let relayer_withheld = sender_transfer
+            .transfer(&relayer_token_account.to_account_info(), params.relayer_fee)?;
+        withheld = withheld.saturating_add(relayer_withheld);
+    }
+
+    // 10. Reimburse payer from the merchant's gas tank with the signed amount, capped per
+    //     payment by the tank (skipped if the tank is empty)
+    let mut gas_tank_reimbursed = None;
+    if let Some(gas_tank) = accounts.gas_tank {
+        let merchant = accounts.merchant.ok_or(PaymentError::InvalidGasTank)?;
+        require!(
+            accounts.payment_record.is_some(),
+            PaymentError::PaymentRecordRequired
+        );
+        require!(
+            gas_tank.merchant == merchant.owner,
+            PaymentError::InvalidGasTank
+        );
+        require!(
+            params.gas_reimbursement <= gas_tank.reimbursement_per_payment,
+            PaymentError::InvalidGasTank
+        );
+
+        let (reimbursed, balance) = reimburse_from_gas_tank(
+            &gas_tank.to_account_info(),
+            accounts.payer,
+            params.gas_reimbursement,
+        )?;
+        if reimbursed > 0 {
+            gas_tank_reimbursed = Some(GasTankReimbursed {
+                merchant: gas_tank.merchant,
+                payer: accounts.payer.key(),
+                payment_id: params.payment_id,
+                amount: reimbursed,
+                balance,
+            });
+        }
+    } else {
+        require!(params.gas_reimbursement == 0, PaymentError::InvalidGasTank);
+    }
+
+    // 11. Record the payment when a record was passed (its PDA init already rejected a
+    //     reused payment_id)
+    if let (Some(record), Some(bump)) = (accounts.payment_record, accounts.payment_record_bump) {
+        record.payment_id = params.payment_id;
+        record.sender = accounts.sender.key();
+        record.slot = clock.slot;
+        record.bump = bump;
+    }
+
+    // 12. Emit permanent on-chain memo (format per Config.memo_format)
+    let memo = SettoMemo::DirectPayment(DirectPaymentMemo {
+        payment_id: params.payment_id,
+        sender: accounts.sender.key(),
+        recipient: accounts.recipient_token_account.owner,
+        total_amount: params.total_amount,
+        amount: params.amount,
+        protocol_fee: params.protocol_fee,
+        relayer: params.relayer,
+        relayer_fee: params.relayer_fee,
+        transfer_fee_mode: params.transfer_fee_mode,
+        transfer_fee_withheld: withheld,
+        reference: params.reference.clone(),
+        reference_keys: reference_keys.clone(),
+    });
+    emit_memo(
+        accounts.memo_program,
+        &memo.render(accounts.config.memo_format),
+    )?;
+
+    let kind = match accounts.authority {
+        SenderAuthority::Sender(_) => PaymentKind::Direct,
+        _ => PaymentKind::DirectDelegated,
+    };
+    Ok(DirectPaymentEvents {
+        settled: DirectPaymentSettled {
+            payment_id: params.payment_id,
+            kind,
+            sender: accounts.sender.key(),
+            recipient: accounts.recipient_token_account.owner,
+            recipient_token_account: accounts.recipient_token_account.key(),
+            mint: accounts.token_mint.key(),
+            total_amount: params.total_amount,
+            amount: params.amount,
+            protocol_fee: params.protocol_fee,
+            fee_wallet: params.fee_wallet,
+            relayer: params.relayer,
+            relayer_fee: params.relayer_fee,
+            transfer_fee_mode: params.transfer_fee_mode,
+            transfer_fee_withheld: withheld,
+            server_signer: params.server_signer,
+            reference: params.reference.clone(),
+            reference_keys,
+            slot: clock.slot,
+        },
+        gas_tank_reimbursed,
+    })
+}
+
+/// Accounts shared by both pool payment variants (sender- or delegate-signed)
+pub struct PoolPaymentAccounts<'a, 'info> {
+    pub sender: &'a AccountInfo<'info>,
+    pub authority: SenderAuthority<'a, 'info>,
+    pub config: &'a Config,
+    pub token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub mint_config: &'a MintConfig,
+    pub sender_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub pool: &'a mut Account<'info, Pool>,
+    pub pool_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    /// Ledger entry for the recipient (initialized on its first payment)
+    pub pool_ledger: &'a mut PoolLedger,
+    pub pool_ledger_bump: u8,
+    /// Freshly initialized record for params.payment_id (replay protection)
+    pub payment_record: &'a mut PoolPaymentRecord,
+    pub payment_record_bump: u8,
+    pub relayer_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub memo_program: &'a AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub instructions_sysvar: &'a AccountInfo<'info>,
+    /// Solana Pay reference keys followed by Token-2022 TransferHook extra accounts
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Validate, verify, collect, credit and record a pool payment.
+///
+/// sender → pool vault (amount + serviceFee) + sender → relayer (relayerFee);
+/// the recipient's ledger is credited with the amount the vault received for it.
+/// Returns the event the handler emits via emit_cpi!.
+pub fn execute_pool_payment(
+    accounts: PoolPaymentAccounts,
+    params: &PoolPaymentParams,
+) -> Result<PoolPaymentSettled> {
+    // 1. Deadline validation
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= params.deadline,
+        PaymentError::PaymentExpired
+    );
+
+    // 2. Amount validation (checked_add — overflow-safe)
+    let expected_total = params
+        .amount
+        .checked_add(params.service_fee)
+        .and_then(|total| total.checked_add(params.relayer_fee))
+        .ok_or(PaymentError::AmountMismatch)?;
+    require!(
+        params.total_amount == expected_total,
+        PaymentError::AmountMismatch
+    );
+    require!(params.amount > 0, PaymentError::InvalidAmount);
+    require!(
+        params.reference.len() <= MAX_REFERENCE_LEN,
+        PaymentError::InvalidReference
+    );
+    let (reference_keys, transfer_hook_accounts) =
+        split_reference_keys(accounts.remaining_accounts, params.reference_key_count)?;
+
+    // 3. Mint allowlist validation (enabled, kind, delegated pulls, amount limits)
+    validate_mint_config(
+        accounts.mint_config,
+        MintConfig::KIND_POOL,
+        matches!(accounts.authority, SenderAuthority::Delegate(..)),
+        params.amount,
+    )?;
+
+    // 4. Destination validation (chain ID + address format)
+    validate_pool_recipient(
+        params.destination_chain_id,
+        params.address_format,
+        &params.recipient,
+    )?;
+
+    // 5. Server signature verification (Ed25519)
+    let pool_key = accounts.pool.key();
+    let message = build_pool_payment_message(&PoolPaymentMessage {
+        payment_id: &params.payment_id,
+        sender: accounts.sender.key,
+        pool: &pool_key,
+        recipient: &params.recipient,
+        token_mint: &accounts.token_mint.key(),
+        total_amount: params.total_amount,
+        amount: params.amount,
+        service_fee: params.service_fee,
+        destination_chain_id: params.destination_chain_id,
+        address_format: params.address_format,
+        relayer: &params.relayer,
+        relayer_fee: params.relayer_fee,
+        transfer_fee_mode: params.transfer_fee_mode,
+        deadline: params.deadline,
+        reference: &params.reference,
+        reference_keys: &reference_keys,
+    });
+    verify_server_signature(
+        accounts.instructions_sysvar,
+        &params.server_signer,
+        &message,
+    )?;
+
+    // 6. Transfer sender → pool (amount + serviceFee)
+    let vault_amount = params.total_amount - params.relayer_fee;
+    let sender_transfer = SenderTransfer {
+        token_program: accounts.token_program.to_account_info(),
+        from: accounts.sender_token_account.to_account_info(),
+        mint: accounts.token_mint.to_account_info(),
+        authority: accounts.authority,
+        decimals: accounts.token_mint.decimals,
+        transfer_fee: mint_transfer_fee_config(&accounts.token_mint.to_account_info())?,
+        transfer_fee_mode: params.transfer_fee_mode,
+        epoch: clock.epoch,
+        transfer_hook_accounts,
+    };
+    let vault_withheld =
+        sender_transfer.transfer(&accounts.pool_vault.to_account_info(), vault_amount)?;
+
+    // Net: the withheld fee comes out of the recipient's amount (service fee stays whole)
+    let (vault_received, credited_amount) = match params.transfer_fee_mode {
+        TransferFeeMode::Net => (
+            vault_amount - vault_withheld,
+            params
+                .amount
+                .checked_sub(vault_withheld)
+                .filter(|credited| *credited > 0)
+                .ok_or(PaymentError::InvalidAmount)?,
+        ),
+        TransferFeeMode::GrossUp => (vault_amount, params.amount),
+    };
+
+    // 7. Reimburse relayer in the payment token
+    let mut withheld = vault_withheld;
+    if params.relayer_fee > 0 {
+        let relayer_token_account = accounts
+            .relayer_token_account
+            .ok_or(PaymentError::RelayerAccountRequired)?;
+
+        let relayer_withheld = sender_transfer
+            .transfer(&relayer_token_account.to_account_info(), params.relayer_fee)?;
+        withheld = withheld.saturating_add(relayer_withheld);
+    }
+
+    // 8. Credit settlement ledger (service_fee stays in the pool as revenue) and record payment
+    let ledger = accounts.pool_ledger;
+    if ledger.pool == Pubkey::default() {
+        ledger.pool = pool_key;
+        ledger.recipient = params.recipient;
+        ledger.destination_chain_id = params.destination_chain_id;
+        ledger.address_format = params.address_format;
+        ledger.bump = accounts.pool_ledger_bump;
+    }
+    ledger.owed = ledger
+        .owed
+        .checked_add(credited_amount)
+        .ok_or(PaymentError::AmountMismatch)?;
+    ledger.total_credited = ledger
+        .total_credited
+        .checked_add(credited_amount)
+        .ok_or(PaymentError::AmountMismatch)?;
+
+    accounts.pool.total_owed = accounts
+        .pool
+        .total_owed
+        .checked_add(credited_amount)
+        .ok_or(PaymentError::AmountMismatch)?;
+
+    let record = accounts.payment_record;
+    record.pool = pool_key;
+    record.payment_id = params.payment_id;
+    record.sender = accounts.sender.key();
+    record.sender_token_account = accounts.sender_token_account.key();
+    record.recipient = params.recipient;
+    record.destination_chain_id = params.destination_chain_id;
+    record.address_format = params.address_format;
+    record.vault_amount = vault_received;
+    record.amount = credited_amount;
+    record.status = PoolPaymentStatus::Paid;
+    record.bump = accounts.payment_record_bump;
+
+    // 9. Emit permanent on-chain memo (format per Config.memo_format)
+    let memo = SettoMemo::PoolPayment(PoolPaymentMemo {
+        payment_id: params.payment_id,
+        sender: accounts.sender.key(),
+        pool: pool_key,
+        recipient: params.recipient,
+        total_amount: params.total_amount,
+        amount: params.amount,
+        service_fee: params.service_fee,
+        destination_chain_id: params.destination_chain_id,
+        address_format: params.address_format,
+        relayer: params.relayer,
+        relayer_fee: params.relayer_fee,
+        transfer_fee_mode: params.transfer_fee_mode,
+        transfer_fee_withheld: withheld,
+        credited_amount,
+        reference: params.reference.clone(),
+        reference_keys: reference_keys.clone(),
+    });
+    emit_memo(
+        accounts.memo_program,
+        &memo.render(accounts.config.memo_format),
+    )?;
+
+    let kind = match accounts.authority {
+        SenderAuthority::Sender(_) => PaymentKind::Pool,
+        _ => PaymentKind::PoolDelegated,
+    };
+    Ok(PoolPaymentSettled {
+        payment_id: params.payment_id,
+        kind,
+        sender: accounts.sender.key(),
+        pool: pool_key,
+        recipient: params.recipient,
+        destination_chain_id: params.destination_chain_id,
+        address_format: params.address_format,
+        mint: accounts.token_mint.key(),
+        total_amount: params.total_amount,
+        amount: params.amount,
+        service_fee: params.service_fee,
+        relayer: params.relayer,
+        relayer_fee: params.relayer_fee,
+        transfer_fee_mode: params.transfer_fee_mode,
+        transfer_fee_withheld: withheld,
+        credited_amount,
+        server_signer: params.server_signer,
+        reference: params.reference.clone(),
+        reference_keys,
+        slot: clock.slot,
+    })
+}
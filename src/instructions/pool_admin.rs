@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use super::payment_core::transfer_checked_with_hooks;
+use crate::errors::PaymentError;
+use crate::events::{PoolActiveChanged, PoolCreated, PoolWithdrawn};
+use crate::state::{Config, Pool, Role, RoleAssignment};
+
+// ============================================
+// Create Pool (Authority or PoolOperator Role)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreatePool<'info> {
+    #[account(
+        mut,
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::PoolOperator, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Pool operator (settlement service wallet)
+    /// CHECK: Just storing the address, validated in handler
+    pub operator: UncheckedAccount<'info>,
+
+    /// Token mint accepted by the pool
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [Pool::SEED, operator.key().as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Program-owned vault — token authority is the Pool PDA
+    #[account(
+        init,
+        payer = manager,
+        seeds = [Pool::VAULT_SEED, pool.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = pool,
+        token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program (SPL Token or Token-2022, auto-validated by Interface)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// PoolOperator RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_pool_handler(ctx: Context<CreatePool>) -> Result<()> {
+    require!(
+        ctx.accounts.operator.key() != Pubkey::default(),
+        PaymentError::InvalidAddress
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    pool.operator = ctx.accounts.operator.key();
+    pool.token_mint = ctx.accounts.token_mint.key();
+    pool.vault = ctx.accounts.pool_vault.key();
+    pool.is_active = true;
+    pool.bump = ctx.bumps.pool;
+    pool.vault_bump = ctx.bumps.pool_vault;
+
+    emit_cpi!(PoolCreated {
+        pool: pool.key(),
+        operator: pool.operator,
+        mint: pool.token_mint,
+        vault: pool.vault,
+        created_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Set Pool Active (Authority or PoolOperator Role)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPoolActive<'info> {
+    #[account(
+        constraint = manager.key() == config.authority
+            || RoleAssignment::grants(role_assignment.as_deref(), Role::PoolOperator, &manager.key())
+            @ PaymentError::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.operator.as_ref(), pool.token_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// PoolOperator RoleAssignment of the signer (omit when signing as authority)
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+pub fn set_pool_active_handler(ctx: Context<SetPoolActive>, is_active: bool) -> Result<()> {
+    ctx.accounts.pool.is_active = is_active;
+    emit_cpi!(PoolActiveChanged {
+        pool: ctx.accounts.pool.key(),
+        is_active,
+        changed_by: ctx.accounts.manager.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Withdraw From Pool (Authority or Pool Operator)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawFromPool<'info> {
+    /// Config.authority or the pool's operator
+    #[account(
+        constraint = signer.key() == config.authority
+            || signer.key() == pool.operator @ PaymentError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [Pool::SEED, pool.operator.as_ref(), pool.token_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Pool's program-owned vault (source)
+    #[account(
+        mut,
+        address = pool.vault @ PaymentError::InvalidPoolVault
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token mint of the pool
+    #[account(address = pool.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Settlement destination token account
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == token_mint.key()
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program (SPL Token or Token-2022, auto-validated by Interface)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_from_pool_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawFromPool<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, PaymentError::InvalidAmount);
+
+    // Funds owed to tracked recipients are reserved for settle_pool
+    let available = ctx
+        .accounts
+        .pool_vault
+        .amount
+        .saturating_sub(ctx.accounts.pool.total_owed);
+    require!(available >= amount, PaymentError::InsufficientPoolBalance);
+
+    // Pool PDA is the vault's token authority
+    let pool = &ctx.accounts.pool;
+    let pool_seeds = &[
+        Pool::SEED,
+        pool.operator.as_ref(),
+        pool.token_mint.as_ref(),
+        &[pool.bump],
+    ];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    transfer_checked_with_hooks(
+        &ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.token_mint.decimals,
+        None,
+        signer_seeds,
+    )?;
+
+    emit_cpi!(PoolWithdrawn {
+        pool: ctx.accounts.pool.key(),
+        destination_token_account: ctx.accounts.destination_token_account.key(),
+        mint: ctx.accounts.token_mint.key(),
+        amount,
+        withdrawn_by: ctx.accounts.signer.key(),
+    });
+    Ok(())
+}
@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use super::utils::{build_pool_payment_message, emit_memo, verify_server_signature};
+use super::payment_core::{
+    execute_pool_payment, PoolPaymentAccounts, SenderAuthority, TransferFeeMode,
+};
+use super::pool_payment::PoolPaymentParams;
 use crate::errors::PaymentError;
-use crate::state::{Config, Delegate, ServerSigner};
+use crate::state::{
+    AddressFormat, Config, Delegate, MintConfig, Pool, PoolLedger, PoolPaymentRecord, ServerSigner,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: PoolPaymentDelegatedParams)]
 pub struct ProcessPoolPaymentDelegated<'info> {
@@ -19,7 +25,7 @@ pub struct ProcessPoolPaymentDelegated<'info> {
     #[account(
         seeds = [Config::SEED],
         bump = config.bump,
-        constraint = !config.paused @ PaymentError::Paused
+        constraint = !config.is_paused(Config::PAUSE_POOL_DELEGATED) @ PaymentError::PoolDelegatedPaymentsPaused
     )]
     pub config: Account<'info, Config>,
 
@@ -34,13 +40,21 @@ pub struct ProcessPoolPaymentDelegated<'info> {
     #[account(
         seeds = [ServerSigner::SEED, params.server_signer.as_ref()],
         bump = server_signer_account.bump,
-        constraint = server_signer_account.is_active @ PaymentError::UnauthorizedServerSigner
+        constraint = server_signer_account.is_active @ PaymentError::UnauthorizedServerSigner,
+        constraint = !server_signer_account.paused @ PaymentError::ServerSignerPaused
     )]
     pub server_signer_account: Account<'info, ServerSigner>,
 
     /// Token mint (SPL Token or Token-2022)
     pub token_mint: InterfaceAccount<'info, Mint>,
 
+    /// Mint allowlist entry — mint must be enabled for this payment kind
+    #[account(
+        seeds = [MintConfig::SEED, token_mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
     /// Sender's token account (source)
     /// Must have delegate set to Delegate PDA with sufficient delegated_amount
     #[account(
@@ -53,12 +67,58 @@ pub struct ProcessPoolPaymentDelegated<'info> {
     )]
     pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// Pool's token account (receives totalAmount — single transfer)
+    /// Registered pool — must be active and accept this token mint
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.operator.as_ref(), pool.token_mint.as_ref()],
+        bump = pool.bump,
+        constraint = pool.is_active @ PaymentError::PoolInactive,
+        constraint = pool.token_mint == token_mint.key() @ PaymentError::InvalidPoolVault
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Pool's program-owned vault (receives amount + serviceFee)
     #[account(
         mut,
-        constraint = pool_token_account.mint == token_mint.key()
+        address = pool.vault @ PaymentError::InvalidPoolVault
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Settlement ledger entry for the tracked recipient on its chain (credited with the
+    /// amount the vault actually received for it)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PoolLedger::INIT_SPACE,
+        seeds = [
+            PoolLedger::SEED,
+            pool.key().as_ref(),
+            params.destination_chain_id.to_le_bytes().as_ref(),
+            &[params.address_format as u8],
+            params.recipient.as_ref()
+        ],
+        bump
     )]
-    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub pool_ledger: Account<'info, PoolLedger>,
+
+    /// Payment record (refund source of truth; init rejects a reused payment_id)
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PoolPaymentRecord::INIT_SPACE,
+        seeds = [PoolPaymentRecord::SEED, pool.key().as_ref(), params.payment_id.as_ref()],
+        bump
+    )]
+    pub payment_record: Account<'info, PoolPaymentRecord>,
+
+    /// Relayer's token account (receives relayer_fee)
+    /// Optional — required only when relayer_fee > 0
+    #[account(
+        mut,
+        constraint = relayer_token_account.owner == params.relayer @ PaymentError::InvalidRelayer,
+        constraint = relayer_token_account.mint == token_mint.key()
+    )]
+    pub relayer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// Memo program for permanent on-chain logging
     /// CHECK: Validated by address constraint
@@ -72,6 +132,8 @@ pub struct ProcessPoolPaymentDelegated<'info> {
     /// CHECK: Validated by address constraint
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -81,91 +143,78 @@ pub struct PoolPaymentDelegatedParams {
     pub amount: u64,
     pub service_fee: u64,
     /// Recipient address for tracking (EVM 20B zero-padded or SVM 32B)
-    /// Not an on-chain account — stored in memo only
+    /// Not an on-chain account — stored in memo and used as the PoolLedger key
     pub recipient: [u8; 32],
+    /// Chain the recipient address lives on (settlement destination)
+    pub destination_chain_id: u64,
+    /// Format of `recipient` — EVM addresses must have 12 zero leading bytes
+    pub address_format: AddressFormat,
+    /// Relayer reimbursed with relayer_fee (covered by the server signature)
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+    /// How Token-2022 transfer fees are absorbed (covered by the server signature)
+    pub transfer_fee_mode: TransferFeeMode,
+    /// Merchant order reference (≤ 64 bytes, covered by the server signature)
+    pub reference: Vec<u8>,
+    /// Leading remaining accounts that are Solana Pay reference keys (≤ 4,
+    /// covered by the server signature); the rest are TransferHook accounts
+    pub reference_key_count: u8,
     pub deadline: i64,
     pub server_signer: Pubkey,
     pub server_signature: [u8; 64],
 }
 
-pub fn process_pool_payment_delegated_handler(
-    ctx: Context<ProcessPoolPaymentDelegated>,
+impl From<PoolPaymentDelegatedParams> for PoolPaymentParams {
+    fn from(params: PoolPaymentDelegatedParams) -> Self {
+        Self {
+            payment_id: params.payment_id,
+            total_amount: params.total_amount,
+            amount: params.amount,
+            service_fee: params.service_fee,
+            recipient: params.recipient,
+            destination_chain_id: params.destination_chain_id,
+            address_format: params.address_format,
+            relayer: params.relayer,
+            relayer_fee: params.relayer_fee,
+            transfer_fee_mode: params.transfer_fee_mode,
+            reference: params.reference,
+            reference_key_count: params.reference_key_count,
+            deadline: params.deadline,
+            server_signer: params.server_signer,
+            server_signature: params.server_signature,
+        }
+    }
+}
+
+pub fn process_pool_payment_delegated_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessPoolPaymentDelegated<'info>>,
     params: PoolPaymentDelegatedParams,
 ) -> Result<()> {
-    // 1. Deadline validation
-    let clock = Clock::get()?;
-    require!(
-        clock.unix_timestamp <= params.deadline,
-        PaymentError::PaymentExpired
-    );
-
-    // 2. Amount validation (checked_add — overflow-safe)
-    let expected_total = params
-        .amount
-        .checked_add(params.service_fee)
-        .ok_or(PaymentError::AmountMismatch)?;
-    require!(params.total_amount == expected_total, PaymentError::AmountMismatch);
-    require!(params.amount > 0, PaymentError::InvalidAmount);
-
-    // 3. Server signature verification (Ed25519)
-    let message = build_pool_payment_message(
-        &params.payment_id,
-        &ctx.accounts.sender.key(),
-        &ctx.accounts.pool_token_account.owner,
-        &params.recipient,
-        &ctx.accounts.token_mint.key(),
-        params.total_amount,
-        params.amount,
-        params.service_fee,
-        params.deadline,
-    );
-    verify_server_signature(
-        &ctx.accounts.instructions_sysvar,
-        &params.server_signer,
-        &message,
+    let delegate = ctx.accounts.delegate.to_account_info();
+
+    let settled = execute_pool_payment(
+        PoolPaymentAccounts {
+            sender: &ctx.accounts.sender,
+            authority: SenderAuthority::Delegate(&delegate, ctx.accounts.delegate.bump),
+            config: &ctx.accounts.config,
+            token_mint: &ctx.accounts.token_mint,
+            mint_config: &ctx.accounts.mint_config,
+            sender_token_account: &ctx.accounts.sender_token_account,
+            pool: &mut ctx.accounts.pool,
+            pool_vault: &ctx.accounts.pool_vault,
+            pool_ledger: &mut ctx.accounts.pool_ledger,
+            pool_ledger_bump: ctx.bumps.pool_ledger,
+            payment_record: &mut ctx.accounts.payment_record,
+            payment_record_bump: ctx.bumps.payment_record,
+            relayer_token_account: ctx.accounts.relayer_token_account.as_ref(),
+            memo_program: &ctx.accounts.memo_program,
+            token_program: &ctx.accounts.token_program,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        &params.into(),
     )?;
 
-    // 4. Single transfer: sender → pool (totalAmount) using Delegate PDA
-    let delegate_seeds = &[Delegate::SEED, &[ctx.accounts.delegate.bump]];
-    let signer_seeds = &[&delegate_seeds[..]];
-
-    token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.sender_token_account.to_account_info(),
-                mint: ctx.accounts.token_mint.to_account_info(),
-                to: ctx.accounts.pool_token_account.to_account_info(),
-                authority: ctx.accounts.delegate.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        params.total_amount,
-        ctx.accounts.token_mint.decimals,
-    )?;
-
-    // 5. Emit permanent on-chain memo
-    let payment_id_hex = params
-        .payment_id
-        .iter()
-        .map(|b| format!("{:02x}", b))
-        .collect::<String>();
-    let recipient_hex = params
-        .recipient
-        .iter()
-        .map(|b| format!("{:02x}", b))
-        .collect::<String>();
-    let memo = format!(
-        "POOL_PAYMENT|{}|{}|{}|{}|{}|{}|{}",
-        payment_id_hex,
-        ctx.accounts.sender.key(),
-        ctx.accounts.pool_token_account.owner,
-        recipient_hex,
-        params.total_amount,
-        params.amount,
-        params.service_fee,
-    );
-    emit_memo(&ctx.accounts.memo_program, &memo)?;
-
+    emit_cpi!(settled);
     Ok(())
 }
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use super::payment_core::{
+    mint_transfer_fee_config, SenderAuthority, SenderTransfer, TransferFeeMode,
+};
+use super::utils::{
+    build_pool_refund_message, emit_memo, verify_server_signature, PoolRefundMessage,
+};
+use crate::errors::PaymentError;
+use crate::events::PoolPaymentRefunded;
+use crate::memo::{PoolRefundMemo, SettoMemo};
+use crate::state::{Config, Pool, PoolLedger, PoolPaymentRecord, PoolPaymentStatus, ServerSigner};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: RefundPoolPaymentParams)]
+pub struct RefundPoolPayment<'info> {
+    /// Pool operator — authorises the refund together with the server signature
+    #[account(
+        constraint = operator.key() == pool.operator @ PaymentError::Unauthorized
+    )]
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(Config::PAUSE_SETTLEMENT) @ PaymentError::Paused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Server signer PDA — validates the signature came from an authorized signer
+    #[account(
+        seeds = [ServerSigner::SEED, params.server_signer.as_ref()],
+        bump = server_signer_account.bump,
+        constraint = server_signer_account.is_active @ PaymentError::UnauthorizedServerSigner,
+        constraint = !server_signer_account.paused @ PaymentError::ServerSignerPaused
+    )]
+    pub server_signer_account: Account<'info, ServerSigner>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.operator.as_ref(), pool.token_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Payment being refunded — must be neither settled nor already refunded
+    #[account(
+        mut,
+        seeds = [PoolPaymentRecord::SEED, pool.key().as_ref(), params.payment_id.as_ref()],
+        bump = payment_record.bump,
+        constraint = payment_record.status != PoolPaymentStatus::Settled @ PaymentError::PaymentAlreadySettled,
+        constraint = payment_record.status != PoolPaymentStatus::Refunded @ PaymentError::PaymentAlreadyRefunded
+    )]
+    pub payment_record: Account<'info, PoolPaymentRecord>,
+
+    /// Ledger entry credited by the payment
+    #[account(
+        mut,
+        seeds = [
+            PoolLedger::SEED,
+            pool.key().as_ref(),
+            payment_record.destination_chain_id.to_le_bytes().as_ref(),
+            &[payment_record.address_format as u8],
+            payment_record.recipient.as_ref()
+        ],
+        bump = pool_ledger.bump
+    )]
+    pub pool_ledger: Account<'info, PoolLedger>,
+
+    /// Pool's program-owned vault (source)
+    #[account(
+        mut,
+        address = pool.vault @ PaymentError::InvalidPoolVault
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token mint of the pool
+    #[account(address = pool.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Original sender's token account (receives the refund)
+    #[account(
+        mut,
+        address = payment_record.sender_token_account @ PaymentError::InvalidAddress
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Memo program for permanent on-chain logging
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_memo::ID)]
+    pub memo_program: AccountInfo<'info>,
+
+    /// Token program (SPL Token or Token-2022, auto-validated by Interface)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Instructions sysvar for Ed25519 signature verification
+    /// CHECK: Validated by address constraint
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RefundPoolPaymentParams {
+    pub payment_id: [u8; 32],
+    /// Net: the sender receives vaultAmount less the Token-2022 transfer fee.
+    /// GrossUp: the fee is paid on top from unreserved vault funds.
+    pub transfer_fee_mode: TransferFeeMode,
+    pub deadline: i64,
+    pub server_signer: Pubkey,
+    pub server_signature: [u8; 64],
+}
+
+pub fn refund_pool_payment_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundPoolPayment<'info>>,
+    params: RefundPoolPaymentParams,
+) -> Result<()> {
+    // 1. Deadline validation
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= params.deadline,
+        PaymentError::PaymentExpired
+    );
+
+    // 2. The credited amount must still be owed
+    let vault_amount = ctx.accounts.payment_record.vault_amount;
+    let amount = ctx.accounts.payment_record.amount;
+    require!(
+        amount <= ctx.accounts.pool_ledger.owed,
+        PaymentError::RefundExceedsOwed
+    );
+
+    // 3. Server signature verification (Ed25519)
+    let message = build_pool_refund_message(&PoolRefundMessage {
+        payment_id: &params.payment_id,
+        pool: &ctx.accounts.pool.key(),
+        refund_to: &ctx.accounts.sender_token_account.key(),
+        token_mint: &ctx.accounts.token_mint.key(),
+        amount: vault_amount,
+        transfer_fee_mode: params.transfer_fee_mode,
+        deadline: params.deadline,
+    });
+    verify_server_signature(
+        &ctx.accounts.instructions_sysvar,
+        &params.server_signer,
+        &message,
+    )?;
+
+    // 4. Transfer vault → sender (vaultAmount, Pool PDA signs). Everything debited beyond
+    //    the credited amount (service fee portion, grossed-up transfer fee) was never
+    //    reserved in total_owed, so it must be covered by unreserved vault funds and the
+    //    refund cannot draw on balances owed to other recipients
+    let pool = &ctx.accounts.pool;
+    let pool_info = pool.to_account_info();
+    let pool_seeds: &[&[u8]] = &[
+        Pool::SEED,
+        pool.operator.as_ref(),
+        pool.token_mint.as_ref(),
+        &[pool.bump],
+    ];
+    let vault_transfer = SenderTransfer {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.pool_vault.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        authority: SenderAuthority::Pool(&pool_info, pool_seeds),
+        decimals: ctx.accounts.token_mint.decimals,
+        transfer_fee: mint_transfer_fee_config(&ctx.accounts.token_mint.to_account_info())?,
+        transfer_fee_mode: params.transfer_fee_mode,
+        epoch: clock.epoch,
+        transfer_hook_accounts: ctx.remaining_accounts,
+    };
+    let (gross, _) = vault_transfer.fee_for(vault_amount)?;
+    let unreserved = ctx
+        .accounts
+        .pool_vault
+        .amount
+        .saturating_sub(pool.total_owed);
+    require!(
+        unreserved >= gross - amount,
+        PaymentError::InsufficientPoolBalance
+    );
+    let transfer_fee_withheld = vault_transfer.transfer(
+        &ctx.accounts.sender_token_account.to_account_info(),
+        vault_amount,
+    )?;
+
+    // 5. Reverse the ledger credit and mark refunded (prevents double refunds)
+    let ledger = &mut ctx.accounts.pool_ledger;
+    ledger.owed = ledger
+        .owed
+        .checked_sub(amount)
+        .ok_or(PaymentError::RefundExceedsOwed)?;
+    ledger.total_credited = ledger
+        .total_credited
+        .checked_sub(amount)
+        .ok_or(PaymentError::RefundExceedsOwed)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.total_owed = pool
+        .total_owed
+        .checked_sub(amount)
+        .ok_or(PaymentError::RefundExceedsOwed)?;
+
+    ctx.accounts.payment_record.status = PoolPaymentStatus::Refunded;
+
+    // 6. Emit permanent on-chain memo (format per Config.memo_format)
+    let memo = SettoMemo::PoolRefund(PoolRefundMemo {
+        payment_id: params.payment_id,
+        pool: ctx.accounts.pool.key(),
+        sender: ctx.accounts.payment_record.sender,
+        amount: vault_amount,
+        transfer_fee_mode: params.transfer_fee_mode,
+        transfer_fee_withheld,
+    });
+    emit_memo(
+        &ctx.accounts.memo_program,
+        &memo.render(ctx.accounts.config.memo_format),
+    )?;
+
+    emit_cpi!(PoolPaymentRefunded {
+        payment_id: params.payment_id,
+        pool: ctx.accounts.pool.key(),
+        sender: ctx.accounts.payment_record.sender,
+        sender_token_account: ctx.accounts.sender_token_account.key(),
+        mint: ctx.accounts.token_mint.key(),
+        amount: vault_amount,
+        transfer_fee_mode: params.transfer_fee_mode,
+        transfer_fee_withheld,
+        server_signer: params.server_signer,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use super::payment_core::{
+    mint_transfer_fee_config, SenderAuthority, SenderTransfer, TransferFeeMode,
+};
+use super::utils::{
+    build_pool_settlement_message, emit_memo, verify_server_signature, PoolSettlementMessage,
+};
+use crate::errors::PaymentError;
+use crate::events::PoolSettled;
+use crate::memo::{PoolSettlementMemo, SettoMemo};
+use crate::state::{
+    AddressFormat, Config, Pool, PoolLedger, PoolPaymentRecord, PoolPaymentStatus, ServerSigner,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SettlePoolParams)]
+pub struct SettlePool<'info> {
+    /// Payer for transaction fees (anyone — server signature authorizes the payout)
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(Config::PAUSE_SETTLEMENT) @ PaymentError::Paused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Server signer PDA — validates the signature came from an authorized signer
+    #[account(
+        seeds = [ServerSigner::SEED, params.server_signer.as_ref()],
+        bump = server_signer_account.bump,
+        constraint = server_signer_account.is_active @ PaymentError::UnauthorizedServerSigner,
+        constraint = !server_signer_account.paused @ PaymentError::ServerSignerPaused
+    )]
+    pub server_signer_account: Account<'info, ServerSigner>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.operator.as_ref(), pool.token_mint.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Ledger entry of the recipient being paid out
+    #[account(
+        mut,
+        seeds = [
+            PoolLedger::SEED,
+            pool.key().as_ref(),
+            params.destination_chain_id.to_le_bytes().as_ref(),
+            &[params.address_format as u8],
+            params.recipient.as_ref()
+        ],
+        bump = pool_ledger.bump
+    )]
+    pub pool_ledger: Account<'info, PoolLedger>,
+
+    /// Pool's program-owned vault (source)
+    #[account(
+        mut,
+        address = pool.vault @ PaymentError::InvalidPoolVault
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token mint of the pool
+    #[account(address = pool.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Payout token account (owner is covered by the server signature)
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == token_mint.key()
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Memo program for permanent on-chain logging
+    /// CHECK: Validated by address constraint
+    #[account(address = spl_memo::ID)]
+    pub memo_program: AccountInfo<'info>,
+
+    /// Token program (SPL Token or Token-2022, auto-validated by Interface)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Instructions sysvar for Ed25519 signature verification
+    /// CHECK: Validated by address constraint
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SettlePoolParams {
+    /// Ledger key (same values as in PoolPaymentParams)
+    pub recipient: [u8; 32],
+    pub destination_chain_id: u64,
+    pub address_format: AddressFormat,
+    /// Debited from the ledger
+    pub amount: u64,
+    /// Net: the destination receives amount less the Token-2022 transfer fee.
+    /// GrossUp: the fee is paid on top from unreserved vault funds.
+    pub transfer_fee_mode: TransferFeeMode,
+    /// Pool payments paid out (≤ 8, covered by the server signature); their records
+    /// are the leading remaining accounts, in order, followed by TransferHook accounts
+    pub payment_ids: Vec<[u8; 32]>,
+    pub deadline: i64,
+    pub server_signer: Pubkey,
+    pub server_signature: [u8; 64],
+}
+
+pub fn settle_pool_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettlePool<'info>>,
+    params: SettlePoolParams,
+) -> Result<()> {
+    // 1. Deadline validation
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= params.deadline,
+        PaymentError::PaymentExpired
+    );
+
+    // 2. Amount validation — never pay out more than the ledger owes
+    require!(params.amount > 0, PaymentError::InvalidAmount);
+    require!(
+        params.amount <= ctx.accounts.pool_ledger.owed,
+        PaymentError::SettlementExceedsOwed
+    );
+
+    // 3. Settled payments — unsettled, unrefunded payments to this ledger entry summing to amount
+    let payment_count = params.payment_ids.len();
+    require!(
+        payment_count > 0
+            && payment_count <= PoolPaymentRecord::MAX_SETTLEMENT_PAYMENTS
+            && payment_count <= ctx.remaining_accounts.len(),
+        PaymentError::InvalidSettlementPayments
+    );
+    let (record_accounts, transfer_hook_accounts) = ctx.remaining_accounts.split_at(payment_count);
+
+    let pool_key = ctx.accounts.pool.key();
+    let ledger = &ctx.accounts.pool_ledger;
+    let mut records = Vec::with_capacity(payment_count);
+    let mut settled_amount: u64 = 0;
+    for (i, (info, payment_id)) in record_accounts.iter().zip(&params.payment_ids).enumerate() {
+        require!(
+            !params.payment_ids[..i].contains(payment_id) && info.is_writable,
+            PaymentError::InvalidSettlementPayments
+        );
+        let record = Account::<PoolPaymentRecord>::try_from(info)?;
+        require!(
+            record.pool == pool_key
+                && record.payment_id == *payment_id
+                && record.recipient == ledger.recipient
+                && record.destination_chain_id == ledger.destination_chain_id
+                && record.address_format == ledger.address_format,
+            PaymentError::InvalidSettlementPayments
+        );
+        require!(
+            record.status != PoolPaymentStatus::Settled,
+            PaymentError::PaymentAlreadySettled
+        );
+        require!(
+            record.status != PoolPaymentStatus::Refunded,
+            PaymentError::PaymentAlreadyRefunded
+        );
+        settled_amount = settled_amount
+            .checked_add(record.amount)
+            .ok_or(PaymentError::AmountMismatch)?;
+        records.push(record);
+    }
+    require!(
+        settled_amount == params.amount,
+        PaymentError::AmountMismatch
+    );
+
+    // 4. Server signature verification (Ed25519)
+    let message = build_pool_settlement_message(&PoolSettlementMessage {
+        pool: &ctx.accounts.pool.key(),
+        recipient: &params.recipient,
+        destination_chain_id: params.destination_chain_id,
+        address_format: params.address_format,
+        destination: &ctx.accounts.destination_token_account.owner,
+        token_mint: &ctx.accounts.token_mint.key(),
+        amount: params.amount,
+        transfer_fee_mode: params.transfer_fee_mode,
+        settlement_nonce: ctx.accounts.pool_ledger.settlement_nonce,
+        deadline: params.deadline,
+        payment_ids: &params.payment_ids,
+    });
+    verify_server_signature(
+        &ctx.accounts.instructions_sysvar,
+        &params.server_signer,
+        &message,
+    )?;
+
+    // 5. Transfer vault → destination (Pool PDA signs); a grossed-up transfer fee
+    //    must come from unreserved vault funds, never from balances owed to others
+    let pool = &ctx.accounts.pool;
+    let pool_info = pool.to_account_info();
+    let pool_seeds: &[&[u8]] = &[
+        Pool::SEED,
+        pool.operator.as_ref(),
+        pool.token_mint.as_ref(),
+        &[pool.bump],
+    ];
+    let vault_transfer = SenderTransfer {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.pool_vault.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        authority: SenderAuthority::Pool(&pool_info, pool_seeds),
+        decimals: ctx.accounts.token_mint.decimals,
+        transfer_fee: mint_transfer_fee_config(&ctx.accounts.token_mint.to_account_info())?,
+        transfer_fee_mode: params.transfer_fee_mode,
+        epoch: clock.epoch,
+        transfer_hook_accounts,
+    };
+    let (gross, _) = vault_transfer.fee_for(params.amount)?;
+    let unreserved = ctx
+        .accounts
+        .pool_vault
+        .amount
+        .saturating_sub(pool.total_owed);
+    require!(
+        unreserved >= gross - params.amount,
+        PaymentError::InsufficientPoolBalance
+    );
+    let transfer_fee_withheld = vault_transfer.transfer(
+        &ctx.accounts.destination_token_account.to_account_info(),
+        params.amount,
+    )?;
+
+    // 6. Mark payments settled and debit ledger (bumping the nonce invalidates the signed message)
+    for record in records.iter_mut() {
+        record.status = PoolPaymentStatus::Settled;
+        record.exit(&crate::ID)?;
+    }
+
+    let ledger = &mut ctx.accounts.pool_ledger;
+    let settlement_nonce = ledger.settlement_nonce;
+    ledger.owed = ledger
+        .owed
+        .checked_sub(params.amount)
+        .ok_or(PaymentError::SettlementExceedsOwed)?;
+    ledger.total_settled = ledger
+        .total_settled
+        .checked_add(params.amount)
+        .ok_or(PaymentError::AmountMismatch)?;
+    ledger.settlement_nonce = settlement_nonce
+        .checked_add(1)
+        .ok_or(PaymentError::AmountMismatch)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.total_owed = pool
+        .total_owed
+        .checked_sub(params.amount)
+        .ok_or(PaymentError::SettlementExceedsOwed)?;
+
+    // 7. Emit permanent on-chain memo (format per Config.memo_format)
+    let memo = SettoMemo::PoolSettlement(PoolSettlementMemo {
+        pool: ctx.accounts.pool.key(),
+        recipient: params.recipient,
+        destination_chain_id: params.destination_chain_id,
+        address_format: params.address_format,
+        destination: ctx.accounts.destination_token_account.owner,
+        amount: params.amount,
+        transfer_fee_mode: params.transfer_fee_mode,
+        transfer_fee_withheld,
+        settlement_nonce,
+    });
+    emit_memo(
+        &ctx.accounts.memo_program,
+        &memo.render(ctx.accounts.config.memo_format),
+    )?;
+
+    emit_cpi!(PoolSettled {
+        pool: ctx.accounts.pool.key(),
+        recipient: params.recipient,
+        destination_chain_id: params.destination_chain_id,
+        address_format: params.address_format,
+        destination: ctx.accounts.destination_token_account.owner,
+        mint: ctx.accounts.token_mint.key(),
+        amount: params.amount,
+        transfer_fee_mode: params.transfer_fee_mode,
+        transfer_fee_withheld,
+        settlement_nonce,
+        payment_ids: params.payment_ids,
+        server_signer: params.server_signer,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PaymentError;
+use crate::events::{RoleGranted, RoleRevoked};
+use crate::state::{Config, Role, RoleAssignment};
+
+// ============================================
+// Grant Role (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct GrantRole<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wallet receiving the role
+    /// CHECK: Just storing the address, validated in handler
+    pub member: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [RoleAssignment::SEED, &[role as u8], member.key().as_ref()],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_role_handler(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    require!(
+        ctx.accounts.member.key() != Pubkey::default(),
+        PaymentError::InvalidAddress
+    );
+
+    let role_assignment = &mut ctx.accounts.role_assignment;
+    role_assignment.role = role;
+    role_assignment.member = ctx.accounts.member.key();
+    role_assignment.granted_by = ctx.accounts.authority.key();
+    role_assignment.bump = ctx.bumps.role_assignment;
+
+    emit_cpi!(RoleGranted {
+        role,
+        member: ctx.accounts.member.key(),
+        granted_by: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Revoke Role (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoleAssignment::SEED,
+            &[role_assignment.role as u8],
+            role_assignment.member.as_ref()
+        ],
+        bump = role_assignment.bump,
+        close = authority
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+}
+
+pub fn revoke_role_handler(ctx: Context<RevokeRole>) -> Result<()> {
+    emit_cpi!(RoleRevoked {
+        role: ctx.accounts.role_assignment.role,
+        member: ctx.accounts.role_assignment.member,
+        revoked_by: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
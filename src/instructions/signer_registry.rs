@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PaymentError;
+use crate::events::{SignerRegistryCreated, SignerRegistryMaxSignersChanged};
+use crate::state::{Config, ServerSigner, SignerRegistry};
+
+// ============================================
+// Create Signer Registry (Authority Only)
+// ============================================
+
+/// For deployments initialized before the registry existed
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateSignerRegistry<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SignerRegistry::INIT_SPACE,
+        seeds = [SignerRegistry::SEED],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining_accounts: every existing ServerSigner PDA (migrated to the current version)
+pub fn create_signer_registry_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateSignerRegistry<'info>>,
+    max_signers: u8,
+) -> Result<()> {
+    require!(
+        max_signers > 0 && max_signers <= SignerRegistry::MAX_SIGNERS,
+        PaymentError::InvalidMaxSigners
+    );
+
+    let signer_registry = &mut ctx.accounts.signer_registry;
+    signer_registry.signers = Vec::new();
+    signer_registry.max_signers = max_signers;
+    signer_registry.bump = ctx.bumps.signer_registry;
+
+    for info in ctx.remaining_accounts {
+        let server_signer = Account::<ServerSigner>::try_from(info)?;
+        signer_registry.add(server_signer.signer)?;
+        signer_registry.set_active(&server_signer.signer, server_signer.is_active);
+    }
+
+    emit_cpi!(SignerRegistryCreated {
+        max_signers,
+        signer_count: signer_registry.signers.len() as u8,
+    });
+    Ok(())
+}
+
+// ============================================
+// Set Max Signers (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMaxServerSigners<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED],
+        bump = signer_registry.bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+}
+
+pub fn set_max_server_signers_handler(
+    ctx: Context<SetMaxServerSigners>,
+    max_signers: u8,
+) -> Result<()> {
+    let signer_registry = &mut ctx.accounts.signer_registry;
+    require!(
+        max_signers > 0
+            && max_signers <= SignerRegistry::MAX_SIGNERS
+            && max_signers as usize >= signer_registry.signers.len(),
+        PaymentError::InvalidMaxSigners
+    );
+
+    let old_max_signers = signer_registry.max_signers;
+    signer_registry.max_signers = max_signers;
+
+    emit_cpi!(SignerRegistryMaxSignersChanged {
+        old_max_signers,
+        new_max_signers: max_signers,
+    });
+    Ok(())
+}
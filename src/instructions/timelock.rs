@@ -0,0 +1,283 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PaymentError;
+use crate::events::{
+    ActionCancelled, ActionExecuted, ActionScheduled, AuthorityTransferProposed,
+    EmergencyAdminChangeProposed, ServerSignerAdded,
+};
+use crate::state::{AdminActionKind, Config, PendingAction, ServerSigner, SignerRegistry};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ScheduleActionParams {
+    pub kind: AdminActionKind,
+    /// Server signer / emergency admin / authority (Pubkey::default() for SetTimelockDelay)
+    pub target: Pubkey,
+    /// New timelock delay in seconds (SetTimelockDelay only, 0 otherwise)
+    pub delay: i64,
+    /// Earliest execution time, at least now + Config.timelock_delay
+    pub eta: i64,
+}
+
+// ============================================
+// Schedule Action (Authority Only)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ScheduleAction<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ PaymentError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingAction::INIT_SPACE,
+        seeds = [PendingAction::SEED, config.action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn schedule_action_handler(
+    ctx: Context<ScheduleAction>,
+    params: ScheduleActionParams,
+) -> Result<()> {
+    match params.kind {
+        AdminActionKind::SetTimelockDelay => {
+            require!(
+                params.target == Pubkey::default(),
+                PaymentError::InvalidAddress
+            );
+            require!(
+                (0..=Config::MAX_TIMELOCK_DELAY).contains(&params.delay),
+                PaymentError::InvalidTimelockDelay
+            );
+        }
+        _ => {
+            require!(
+                params.target != Pubkey::default(),
+                PaymentError::InvalidAddress
+            );
+            require!(params.delay == 0, PaymentError::InvalidTimelockDelay);
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let earliest_eta = now
+        .checked_add(ctx.accounts.config.timelock_delay)
+        .ok_or(PaymentError::InvalidActionEta)?;
+    require!(params.eta >= earliest_eta, PaymentError::InvalidActionEta);
+
+    let config = &mut ctx.accounts.config;
+    let id = config.action_count;
+    config.action_count = id.checked_add(1).ok_or(PaymentError::AmountMismatch)?;
+
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.id = id;
+    pending_action.kind = params.kind;
+    pending_action.target = params.target;
+    pending_action.delay = params.delay;
+    pending_action.eta = params.eta;
+    pending_action.proposer = ctx.accounts.authority.key();
+    pending_action.bump = ctx.bumps.pending_action;
+
+    emit_cpi!(ActionScheduled {
+        id,
+        kind: params.kind,
+        target: params.target,
+        delay: params.delay,
+        eta: params.eta,
+        proposer: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Execute Action (Anyone, after ETA)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    /// Anyone; pays rent for accounts created by the action
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PendingAction::SEED, pending_action.id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        close = proposer
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// Receives the pending action's rent
+    /// CHECK: Must match pending_action.proposer
+    #[account(mut, address = pending_action.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Required for AddServerSigner, omitted otherwise
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + ServerSigner::INIT_SPACE,
+        seeds = [ServerSigner::SEED, pending_action.target.as_ref()],
+        bump
+    )]
+    pub server_signer_account: Option<Account<'info, ServerSigner>>,
+
+    /// Required for AddServerSigner, omitted otherwise
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED],
+        bump = signer_registry.bump
+    )]
+    pub signer_registry: Option<Account<'info, SignerRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_action_handler(ctx: Context<ExecuteAction>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= ctx.accounts.pending_action.eta,
+        PaymentError::ActionNotReady
+    );
+
+    let id = ctx.accounts.pending_action.id;
+    let kind = ctx.accounts.pending_action.kind;
+    let target = ctx.accounts.pending_action.target;
+    let delay = ctx.accounts.pending_action.delay;
+    let proposer = ctx.accounts.pending_action.proposer;
+
+    // ServerSigner PDA is only created (and registered) by AddServerSigner
+    require!(
+        (kind == AdminActionKind::AddServerSigner) == ctx.accounts.server_signer_account.is_some(),
+        PaymentError::MissingActionAccount
+    );
+    require!(
+        (kind == AdminActionKind::AddServerSigner) == ctx.accounts.signer_registry.is_some(),
+        PaymentError::MissingActionAccount
+    );
+
+    match kind {
+        AdminActionKind::AddServerSigner => {
+            let server_signer = ctx
+                .accounts
+                .server_signer_account
+                .as_mut()
+                .ok_or(PaymentError::MissingActionAccount)?;
+            server_signer.version = ServerSigner::CURRENT_VERSION;
+            server_signer.signer = target;
+            server_signer.is_active = true;
+            server_signer.paused = false;
+            server_signer.bump = ctx
+                .bumps
+                .server_signer_account
+                .ok_or(PaymentError::MissingActionAccount)?;
+            server_signer.updated_at = now;
+            server_signer.updated_by = proposer;
+            ctx.accounts
+                .signer_registry
+                .as_mut()
+                .ok_or(PaymentError::MissingActionAccount)?
+                .add(target)?;
+
+            emit_cpi!(ServerSignerAdded {
+                signer: target,
+                added_by: proposer,
+                emergency: false,
+            });
+        }
+        AdminActionKind::ChangeEmergencyAdmin => {
+            ctx.accounts.config.pending_emergency_admin = target;
+
+            emit_cpi!(EmergencyAdminChangeProposed {
+                authority: proposer,
+                pending_emergency_admin: target,
+            });
+        }
+        AdminActionKind::TransferAuthority => {
+            ctx.accounts.config.pending_authority = target;
+
+            emit_cpi!(AuthorityTransferProposed {
+                authority: proposer,
+                pending_authority: target,
+            });
+        }
+        AdminActionKind::SetTimelockDelay => {
+            ctx.accounts.config.timelock_delay = delay;
+        }
+    }
+
+    emit_cpi!(ActionExecuted {
+        id,
+        kind,
+        target,
+        delay,
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
+
+// ============================================
+// Cancel Action (Authority or Emergency Admin)
+// ============================================
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    #[account(
+        constraint = admin.key() == config.authority
+            || admin.key() == config.emergency_admin @ PaymentError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PendingAction::SEED, pending_action.id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        close = proposer
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// Receives the pending action's rent
+    /// CHECK: Must match pending_action.proposer
+    #[account(mut, address = pending_action.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn cancel_action_handler(ctx: Context<CancelAction>) -> Result<()> {
+    emit_cpi!(ActionCancelled {
+        id: ctx.accounts.pending_action.id,
+        kind: ctx.accounts.pending_action.kind,
+        target: ctx.accounts.pending_action.target,
+        cancelled_by: ctx.accounts.admin.key(),
+    });
+    Ok(())
+}
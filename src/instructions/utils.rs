@@ -3,7 +3,15 @@ use anchor_lang::solana_program::sysvar::instructions::{
     load_current_index_checked, load_instruction_at_checked,
 };
 
+use super::payment_core::TransferFeeMode;
 use crate::errors::PaymentError;
+use crate::state::{AddressFormat, Config, FeeConfig, Merchant, MerchantStatus, MintConfig};
+
+/// Maximum length of a payment's merchant order reference
+pub const MAX_REFERENCE_LEN: usize = 64;
+
+/// Maximum number of Solana Pay reference keys per payment
+pub const MAX_REFERENCE_KEYS: usize = 4;
 
 /// Ed25519 program ID (official Solana precompile)
 pub mod ed25519_program {
@@ -110,7 +118,7 @@ pub fn verify_server_signature(
 
 /// Build the Direct payment message to be signed by the server.
 ///
-/// Format (192 bytes, all little-endian):
+/// Format (307 + referenceLen + 32 * referenceKeyCount bytes, all little-endian):
 /// [0-31]     paymentId ([u8; 32])
 /// [32-63]    sender (Pubkey)
 /// [64-95]    recipient (Pubkey)
@@ -119,67 +127,381 @@ pub fn verify_server_signature(
 /// [160-167]  totalAmount (u64)
 /// [168-175]  amount (u64)
 /// [176-183]  protocolFee (u64)
-/// [184-191]  deadline (i64)
-pub fn build_direct_payment_message(
-    payment_id: &[u8; 32],
-    sender: &Pubkey,
-    recipient: &Pubkey,
+/// [184-215]  relayer (Pubkey) — receives relayerFee
+/// [216-223]  relayerFee (u64)
+/// [224]      transferFeeMode (u8) — 0 = Net, 1 = GrossUp
+/// [225-256]  payer (Pubkey) — fee payer receiving gasReimbursement
+/// [257-288]  gasTank (Pubkey) — merchant gas tank paying it (default Pubkey when none)
+/// [289-296]  gasReimbursement (u64) — lamports, ≤ the tank's per-payment cap
+/// [297-304]  deadline (i64)
+/// [305]      referenceLen (u8)
+/// [306-..]   reference (referenceLen bytes, ≤ 64) — merchant order reference
+/// [..]       referenceKeyCount (u8)
+/// [..]       referenceKeys (Pubkey each, ≤ 4) — Solana Pay reference accounts
+pub fn build_direct_payment_message(fields: &DirectPaymentMessage) -> Vec<u8> {
+    let mut message =
+        Vec::with_capacity(307 + fields.reference.len() + 32 * fields.reference_keys.len());
+    message.extend_from_slice(fields.payment_id);
+    message.extend_from_slice(fields.sender.as_ref());
+    message.extend_from_slice(fields.recipient.as_ref());
+    message.extend_from_slice(fields.fee_wallet.as_ref());
+    message.extend_from_slice(fields.token_mint.as_ref());
+    message.extend_from_slice(&fields.total_amount.to_le_bytes());
+    message.extend_from_slice(&fields.amount.to_le_bytes());
+    message.extend_from_slice(&fields.protocol_fee.to_le_bytes());
+    message.extend_from_slice(fields.relayer.as_ref());
+    message.extend_from_slice(&fields.relayer_fee.to_le_bytes());
+    message.push(fields.transfer_fee_mode as u8);
+    message.extend_from_slice(fields.payer.as_ref());
+    message.extend_from_slice(fields.gas_tank.as_ref());
+    message.extend_from_slice(&fields.gas_reimbursement.to_le_bytes());
+    message.extend_from_slice(&fields.deadline.to_le_bytes());
+    message.push(fields.reference.len() as u8);
+    message.extend_from_slice(fields.reference);
+    message.push(fields.reference_keys.len() as u8);
+    for reference_key in fields.reference_keys {
+        message.extend_from_slice(reference_key.as_ref());
+    }
+    message
+}
+
+/// Fields of the Direct payment message, named to keep same-typed values apart
+pub struct DirectPaymentMessage<'a> {
+    pub payment_id: &'a [u8; 32],
+    pub sender: &'a Pubkey,
+    /// Owner of the recipient token account
+    pub recipient: &'a Pubkey,
+    pub fee_wallet: &'a Pubkey,
+    pub token_mint: &'a Pubkey,
+    pub total_amount: u64,
+    pub amount: u64,
+    pub protocol_fee: u64,
+    pub relayer: &'a Pubkey,
+    pub relayer_fee: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    pub payer: &'a Pubkey,
+    /// Merchant gas tank, or the default Pubkey when none is used
+    pub gas_tank: &'a Pubkey,
+    pub gas_reimbursement: u64,
+    pub deadline: i64,
+    pub reference: &'a [u8],
+    pub reference_keys: &'a [Pubkey],
+}
+
+/// Validate the signed fee wallet and protocol fee against the on-chain fee config.
+///
+/// The per-mint override (["fee_config", mint]) takes precedence when initialized,
+/// otherwise the global config (["fee_config"]) applies. The signed protocol_fee
+/// must equal the configured fee exactly, so a compromised server signer can
+/// neither redirect nor inflate protocol fees.
+pub fn validate_protocol_fee(
+    fee_config: &FeeConfig,
+    mint_fee_config: &AccountInfo,
     fee_wallet: &Pubkey,
-    token_mint: &Pubkey,
-    total_amount: u64,
     amount: u64,
     protocol_fee: u64,
-    deadline: i64,
-) -> Vec<u8> {
-    let mut message = Vec::with_capacity(192);
-    message.extend_from_slice(payment_id);
-    message.extend_from_slice(sender.as_ref());
-    message.extend_from_slice(recipient.as_ref());
-    message.extend_from_slice(fee_wallet.as_ref());
-    message.extend_from_slice(token_mint.as_ref());
-    message.extend_from_slice(&total_amount.to_le_bytes());
-    message.extend_from_slice(&amount.to_le_bytes());
-    message.extend_from_slice(&protocol_fee.to_le_bytes());
-    message.extend_from_slice(&deadline.to_le_bytes());
-    message
+) -> Result<()> {
+    let effective = if mint_fee_config.owner == &crate::ID && !mint_fee_config.data_is_empty() {
+        FeeConfig::try_deserialize(&mut &mint_fee_config.try_borrow_data()?[..])?
+    } else {
+        fee_config.clone()
+    };
+
+    require!(
+        *fee_wallet == effective.fee_wallet,
+        PaymentError::InvalidFeeRecipient
+    );
+    require!(
+        protocol_fee == effective.compute_fee(amount),
+        PaymentError::InvalidProtocolFee
+    );
+    Ok(())
+}
+
+/// Validate a direct payment's recipient against the merchant registry.
+///
+/// - A provided merchant must be active, and the recipient token account must be
+///   its registered settlement account for the mint
+/// - With Config.merchant_registry_required, the merchant account is mandatory
+pub fn validate_merchant(
+    config: &Config,
+    merchant: Option<&Merchant>,
+    token_mint: &Pubkey,
+    recipient_token_account: &Pubkey,
+) -> Result<()> {
+    match merchant {
+        Some(merchant) => {
+            require!(
+                merchant.status == MerchantStatus::Active,
+                PaymentError::MerchantSuspended
+            );
+            require!(
+                merchant.settlement_account(token_mint) == Some(*recipient_token_account),
+                PaymentError::InvalidSettlementAccount
+            );
+        }
+        None => require!(
+            !config.merchant_registry_required,
+            PaymentError::MerchantNotRegistered
+        ),
+    }
+    Ok(())
+}
+
+/// Validate a payment against the mint allowlist entry.
+///
+/// The mint must be enabled, allow the payment kind (and Delegate PDA pulls when
+/// `delegated`), and `amount` must lie within [min_amount, max_amount].
+pub fn validate_mint_config(
+    mint_config: &MintConfig,
+    kind: u8,
+    delegated: bool,
+    amount: u64,
+) -> Result<()> {
+    require!(mint_config.enabled, PaymentError::MintNotEnabled);
+    require!(!mint_config.paused, PaymentError::MintPaused);
+    require!(
+        mint_config.allowed_kinds & kind != 0,
+        PaymentError::PaymentKindNotAllowed
+    );
+    require!(
+        !delegated || mint_config.allow_delegated,
+        PaymentError::DelegatedPaymentNotAllowed
+    );
+    require!(
+        amount >= mint_config.min_amount,
+        PaymentError::AmountBelowMinimum
+    );
+    require!(
+        amount <= mint_config.max_amount,
+        PaymentError::AmountAboveMaximum
+    );
+    Ok(())
+}
+
+/// Split a payment's remaining accounts into Solana Pay reference keys (the leading
+/// `reference_key_count` accounts) and Token-2022 TransferHook extra accounts (the rest).
+pub fn split_reference_keys<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    reference_key_count: u8,
+) -> Result<(Vec<Pubkey>, &'a [AccountInfo<'info>])> {
+    let count = reference_key_count as usize;
+    require!(
+        count <= MAX_REFERENCE_KEYS && count <= remaining_accounts.len(),
+        PaymentError::InvalidReferenceKeys
+    );
+
+    let (reference_accounts, transfer_hook_accounts) = remaining_accounts.split_at(count);
+    let reference_keys = reference_accounts
+        .iter()
+        .map(|account| account.key())
+        .collect();
+    Ok((reference_keys, transfer_hook_accounts))
+}
+
+/// Lamports held by a program-owned account above its rent-exempt minimum.
+pub fn available_lamports(account: &AccountInfo) -> Result<u64> {
+    let rent_exempt = Rent::get()?.minimum_balance(account.data_len());
+    Ok(account.lamports().saturating_sub(rent_exempt))
+}
+
+/// Reimburse the payer from a merchant's gas tank after a successful payment.
+///
+/// Pays `reimbursement` lamports, or nothing if the tank cannot cover it — an
+/// empty tank never fails the payment. Returns (reimbursed, remaining balance).
+pub fn reimburse_from_gas_tank(
+    gas_tank: &AccountInfo,
+    payer: &AccountInfo,
+    reimbursement: u64,
+) -> Result<(u64, u64)> {
+    let available = available_lamports(gas_tank)?;
+    if reimbursement == 0 || available < reimbursement {
+        return Ok((0, available));
+    }
+
+    **gas_tank.try_borrow_mut_lamports()? -= reimbursement;
+    **payer.try_borrow_mut_lamports()? += reimbursement;
+    Ok((reimbursement, available - reimbursement))
 }
 
 /// Build the Pool payment message to be signed by the server.
 ///
-/// Format (192 bytes, all little-endian):
+/// Format (244 + referenceLen + 32 * referenceKeyCount bytes, all little-endian):
 /// [0-31]     paymentId ([u8; 32])
 /// [32-63]    sender (Pubkey)
-/// [64-95]    pool (Pubkey)
+/// [64-95]    pool (Pubkey) — registered Pool PDA (vault authority)
 /// [96-127]   recipient ([u8; 32]) — tracking only, EVM/SVM address
 /// [128-159]  token (Pubkey)
 /// [160-167]  totalAmount (u64)
 /// [168-175]  amount (u64)
 /// [176-183]  serviceFee (u64)
-/// [184-191]  deadline (i64)
-pub fn build_pool_payment_message(
-    payment_id: &[u8; 32],
-    sender: &Pubkey,
-    pool: &Pubkey,
+/// [184-191]  destinationChainId (u64) — chain the recipient lives on
+/// [192]      addressFormat (u8) — 0 = EVM, 1 = SVM
+/// [193-224]  relayer (Pubkey) — receives relayerFee
+/// [225-232]  relayerFee (u64)
+/// [233]      transferFeeMode (u8) — 0 = Net, 1 = GrossUp
+/// [234-241]  deadline (i64)
+/// [242]      referenceLen (u8)
+/// [243-..]   reference (referenceLen bytes, ≤ 64) — merchant order reference
+/// [..]       referenceKeyCount (u8)
+/// [..]       referenceKeys (Pubkey each, ≤ 4) — Solana Pay reference accounts
+pub fn build_pool_payment_message(fields: &PoolPaymentMessage) -> Vec<u8> {
+    let mut message =
+        Vec::with_capacity(244 + fields.reference.len() + 32 * fields.reference_keys.len());
+    message.extend_from_slice(fields.payment_id);
+    message.extend_from_slice(fields.sender.as_ref());
+    message.extend_from_slice(fields.pool.as_ref());
+    message.extend_from_slice(fields.recipient);
+    message.extend_from_slice(fields.token_mint.as_ref());
+    message.extend_from_slice(&fields.total_amount.to_le_bytes());
+    message.extend_from_slice(&fields.amount.to_le_bytes());
+    message.extend_from_slice(&fields.service_fee.to_le_bytes());
+    message.extend_from_slice(&fields.destination_chain_id.to_le_bytes());
+    message.push(fields.address_format as u8);
+    message.extend_from_slice(fields.relayer.as_ref());
+    message.extend_from_slice(&fields.relayer_fee.to_le_bytes());
+    message.push(fields.transfer_fee_mode as u8);
+    message.extend_from_slice(&fields.deadline.to_le_bytes());
+    message.push(fields.reference.len() as u8);
+    message.extend_from_slice(fields.reference);
+    message.push(fields.reference_keys.len() as u8);
+    for reference_key in fields.reference_keys {
+        message.extend_from_slice(reference_key.as_ref());
+    }
+    message
+}
+
+/// Fields of the Pool payment message, named to keep same-typed values apart
+pub struct PoolPaymentMessage<'a> {
+    pub payment_id: &'a [u8; 32],
+    pub sender: &'a Pubkey,
+    pub pool: &'a Pubkey,
+    pub recipient: &'a [u8; 32],
+    pub token_mint: &'a Pubkey,
+    pub total_amount: u64,
+    pub amount: u64,
+    pub service_fee: u64,
+    pub destination_chain_id: u64,
+    pub address_format: AddressFormat,
+    pub relayer: &'a Pubkey,
+    pub relayer_fee: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    pub deadline: i64,
+    pub reference: &'a [u8],
+    pub reference_keys: &'a [Pubkey],
+}
+
+/// Validate a pool payment's tracked recipient against its destination chain.
+///
+/// - destination_chain_id must be non-zero
+/// - EVM: 20B address left-padded with 12 zero bytes, not the zero address
+/// - SVM: 32B address, not the zero address
+pub fn validate_pool_recipient(
+    destination_chain_id: u64,
+    address_format: AddressFormat,
     recipient: &[u8; 32],
-    token_mint: &Pubkey,
-    total_amount: u64,
-    amount: u64,
-    service_fee: u64,
-    deadline: i64,
-) -> Vec<u8> {
-    let mut message = Vec::with_capacity(192);
-    message.extend_from_slice(payment_id);
-    message.extend_from_slice(sender.as_ref());
-    message.extend_from_slice(pool.as_ref());
-    message.extend_from_slice(recipient);
-    message.extend_from_slice(token_mint.as_ref());
-    message.extend_from_slice(&total_amount.to_le_bytes());
-    message.extend_from_slice(&amount.to_le_bytes());
-    message.extend_from_slice(&service_fee.to_le_bytes());
-    message.extend_from_slice(&deadline.to_le_bytes());
+) -> Result<()> {
+    require!(
+        destination_chain_id != 0,
+        PaymentError::InvalidDestinationChain
+    );
+    require!(
+        recipient.iter().any(|b| *b != 0),
+        PaymentError::InvalidRecipientAddress
+    );
+    if address_format == AddressFormat::Evm {
+        require!(
+            recipient[..12].iter().all(|b| *b == 0),
+            PaymentError::InvalidRecipientAddress
+        );
+    }
+    Ok(())
+}
+
+/// Build the Pool settlement message to be signed by the server.
+///
+/// Format (163 + 32 * paymentCount bytes, all little-endian):
+/// [0-31]     pool (Pubkey)
+/// [32-63]    recipient ([u8; 32]) — ledger key, as in the pool payment message
+/// [64-71]    destinationChainId (u64) — ledger key
+/// [72]       addressFormat (u8) — ledger key, 0 = EVM, 1 = SVM
+/// [73-104]   destination (Pubkey) — owner of the payout token account
+/// [105-136]  token (Pubkey)
+/// [137-144]  amount (u64) — debited from the ledger
+/// [145]      transferFeeMode (u8) — 0 = Net, 1 = GrossUp (fee from unreserved vault funds)
+/// [146-153]  settlementNonce (u64) — PoolLedger.settlement_nonce (replay protection)
+/// [154-161]  deadline (i64)
+/// [162]      paymentCount (u8)
+/// [163-..]   paymentIds ([u8; 32] each, ≤ 8) — pool payments paid out (summing to amount)
+pub fn build_pool_settlement_message(fields: &PoolSettlementMessage) -> Vec<u8> {
+    let mut message = Vec::with_capacity(163 + 32 * fields.payment_ids.len());
+    message.extend_from_slice(fields.pool.as_ref());
+    message.extend_from_slice(fields.recipient);
+    message.extend_from_slice(&fields.destination_chain_id.to_le_bytes());
+    message.push(fields.address_format as u8);
+    message.extend_from_slice(fields.destination.as_ref());
+    message.extend_from_slice(fields.token_mint.as_ref());
+    message.extend_from_slice(&fields.amount.to_le_bytes());
+    message.push(fields.transfer_fee_mode as u8);
+    message.extend_from_slice(&fields.settlement_nonce.to_le_bytes());
+    message.extend_from_slice(&fields.deadline.to_le_bytes());
+    message.push(fields.payment_ids.len() as u8);
+    for payment_id in fields.payment_ids {
+        message.extend_from_slice(payment_id);
+    }
     message
 }
 
+/// Fields of the Pool settlement message, named to keep same-typed values apart
+pub struct PoolSettlementMessage<'a> {
+    pub pool: &'a Pubkey,
+    pub recipient: &'a [u8; 32],
+    pub destination_chain_id: u64,
+    pub address_format: AddressFormat,
+    /// Owner of the payout token account
+    pub destination: &'a Pubkey,
+    pub token_mint: &'a Pubkey,
+    pub amount: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    pub settlement_nonce: u64,
+    pub deadline: i64,
+    pub payment_ids: &'a [[u8; 32]],
+}
+
+/// Build the Pool refund message to be signed by the server.
+///
+/// Format (145 bytes, all little-endian):
+/// [0-31]     paymentId ([u8; 32])
+/// [32-63]    pool (Pubkey)
+/// [64-95]    refundTo (Pubkey) — sender token account from the payment record
+/// [96-127]   token (Pubkey)
+/// [128-135]  amount (u64) — vaultAmount from the payment record
+/// [136]      transferFeeMode (u8) — 0 = Net, 1 = GrossUp (fee from unreserved vault funds)
+/// [137-144]  deadline (i64)
+pub fn build_pool_refund_message(fields: &PoolRefundMessage) -> Vec<u8> {
+    let mut message = Vec::with_capacity(145);
+    message.extend_from_slice(fields.payment_id);
+    message.extend_from_slice(fields.pool.as_ref());
+    message.extend_from_slice(fields.refund_to.as_ref());
+    message.extend_from_slice(fields.token_mint.as_ref());
+    message.extend_from_slice(&fields.amount.to_le_bytes());
+    message.push(fields.transfer_fee_mode as u8);
+    message.extend_from_slice(&fields.deadline.to_le_bytes());
+    message
+}
+
+/// Fields of the Pool refund message, named to keep same-typed values apart
+pub struct PoolRefundMessage<'a> {
+    pub payment_id: &'a [u8; 32],
+    pub pool: &'a Pubkey,
+    /// Sender token account from the payment record
+    pub refund_to: &'a Pubkey,
+    pub token_mint: &'a Pubkey,
+    pub amount: u64,
+    pub transfer_fee_mode: TransferFeeMode,
+    pub deadline: i64,
+}
+
 /// Emit a memo via the Memo Program CPI (permanent on-chain record).
 /// Replaces msg! which is prunable by nodes.
 pub fn emit_memo(memo_program: &AccountInfo, memo: &str) -> Result<()> {
@@ -2,10 +2,13 @@ use anchor_lang::prelude::*;
 use solana_security_txt::security_txt;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod memo;
 pub mod state;
 
 use instructions::*;
+use state::{MemoFormat, MerchantStatus, Role};
 
 // Program ID - auto-updated by deploy script (npm run deploy)
 declare_id!("DXxCKeaee3YD1HeA1UcBxTiHGZYFDZQ34Q2bjY87Nyoc");
@@ -29,8 +32,9 @@ pub mod setto_payment {
 
     /// Process a direct payment (user signs)
     /// sender → recipient (amount) + sender → feeWallet (protocolFee)
-    pub fn process_direct_payment(
-        ctx: Context<ProcessDirectPayment>,
+    ///   + sender → relayer (relayerFee) + merchant gas tank → payer (lamports)
+    pub fn process_direct_payment<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessDirectPayment<'info>>,
         params: DirectPaymentParams,
     ) -> Result<()> {
         instructions::direct_payment::process_direct_payment_handler(ctx, params)
@@ -38,8 +42,8 @@ pub mod setto_payment {
 
     /// Process a direct payment via delegate (gasless, user doesn't sign)
     /// Delegate PDA transfers on behalf of user
-    pub fn process_direct_payment_delegated(
-        ctx: Context<ProcessDirectPaymentDelegated>,
+    pub fn process_direct_payment_delegated<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessDirectPaymentDelegated<'info>>,
         params: DirectPaymentDelegatedParams,
     ) -> Result<()> {
         instructions::direct_payment_delegated::process_direct_payment_delegated_handler(
@@ -47,14 +51,34 @@ pub mod setto_payment {
         )
     }
 
+    /// Process a direct payment to wallet addresses (user signs)
+    /// Recipient / fee wallet ATAs are created idempotently, paid by payer
+    pub fn process_direct_payment_with_ata<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessDirectPaymentAta<'info>>,
+        params: DirectPaymentParams,
+    ) -> Result<()> {
+        instructions::direct_payment_ata::process_direct_payment_ata_handler(ctx, params)
+    }
+
+    /// Process a direct payment via delegate to wallet addresses
+    /// Recipient / fee wallet ATAs are created idempotently, paid by payer
+    pub fn process_direct_payment_delegated_with_ata<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessDirectPaymentDelegatedAta<'info>>,
+        params: DirectPaymentDelegatedParams,
+    ) -> Result<()> {
+        instructions::direct_payment_delegated_ata::process_direct_payment_delegated_ata_handler(
+            ctx, params,
+        )
+    }
+
     // ============================================
     // Core Functions — Pool Payment
     // ============================================
 
     /// Process a pool payment (user signs)
-    /// sender → pool (totalAmount), single transfer, no fee split on-chain
-    pub fn process_pool_payment(
-        ctx: Context<ProcessPoolPayment>,
+    /// sender → pool vault (amount + serviceFee) + sender → relayer (relayerFee)
+    pub fn process_pool_payment<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessPoolPayment<'info>>,
         params: PoolPaymentParams,
     ) -> Result<()> {
         instructions::pool_payment::process_pool_payment_handler(ctx, params)
@@ -62,13 +86,118 @@ pub mod setto_payment {
 
     /// Process a pool payment via delegate (gasless, user doesn't sign)
     /// Delegate PDA transfers on behalf of user
-    pub fn process_pool_payment_delegated(
-        ctx: Context<ProcessPoolPaymentDelegated>,
+    pub fn process_pool_payment_delegated<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessPoolPaymentDelegated<'info>>,
         params: PoolPaymentDelegatedParams,
     ) -> Result<()> {
         instructions::pool_payment_delegated::process_pool_payment_delegated_handler(ctx, params)
     }
 
+    /// Pay out owed balance from a pool vault (server signs)
+    /// pool vault → destination (amount), debits the recipient's ledger entry and
+    /// marks the settled pool payments (remaining accounts) as Settled
+    pub fn settle_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettlePool<'info>>,
+        params: SettlePoolParams,
+    ) -> Result<()> {
+        instructions::pool_settlement::settle_pool_handler(ctx, params)
+    }
+
+    /// Refund a pool payment whose off-chain settlement failed
+    /// Pool operator signs + server signs; pool vault → original sender (amount + serviceFee)
+    pub fn refund_pool_payment<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundPoolPayment<'info>>,
+        params: RefundPoolPaymentParams,
+    ) -> Result<()> {
+        instructions::pool_refund::refund_pool_payment_handler(ctx, params)
+    }
+
+    // ============================================
+    // Pool Registry
+    // ============================================
+
+    /// Register a pool and create its program-owned vault
+    pub fn create_pool(ctx: Context<CreatePool>) -> Result<()> {
+        instructions::pool_admin::create_pool_handler(ctx)
+    }
+
+    /// Activate or deactivate a pool (inactive pools reject payments)
+    pub fn set_pool_active(ctx: Context<SetPoolActive>, is_active: bool) -> Result<()> {
+        instructions::pool_admin::set_pool_active_handler(ctx, is_active)
+    }
+
+    /// Withdraw unowed funds (service fees) from a pool vault (authority or pool operator)
+    pub fn withdraw_from_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFromPool<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::pool_admin::withdraw_from_pool_handler(ctx, amount)
+    }
+
+    // ============================================
+    // Merchant Registry (Authority or Merchant Manager)
+    // ============================================
+
+    /// Register a merchant (active, no settlement accounts yet)
+    pub fn register_merchant(ctx: Context<RegisterMerchant>, params: MerchantParams) -> Result<()> {
+        instructions::merchant_admin::register_merchant_handler(ctx, params)
+    }
+
+    /// Update merchant fee tier and display metadata
+    pub fn update_merchant(ctx: Context<UpdateMerchant>, params: MerchantParams) -> Result<()> {
+        instructions::merchant_admin::update_merchant_handler(ctx, params)
+    }
+
+    /// Activate or suspend a merchant (suspended merchants reject direct payments)
+    pub fn set_merchant_status(ctx: Context<UpdateMerchant>, status: MerchantStatus) -> Result<()> {
+        instructions::merchant_admin::set_merchant_status_handler(ctx, status)
+    }
+
+    /// Set the merchant's settlement token account for its mint
+    pub fn set_merchant_settlement_account(
+        ctx: Context<SetMerchantSettlementAccount>,
+    ) -> Result<()> {
+        instructions::merchant_admin::set_merchant_settlement_account_handler(ctx)
+    }
+
+    /// Remove the merchant's settlement token account for a mint
+    pub fn remove_merchant_settlement_account(
+        ctx: Context<UpdateMerchant>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        instructions::merchant_admin::remove_merchant_settlement_account_handler(ctx, mint)
+    }
+
+    // ============================================
+    // Gas Tanks (Merchant Only, deposits from anyone)
+    // ============================================
+
+    /// Create the merchant's gas tank (merchant must be registered)
+    pub fn create_gas_tank(
+        ctx: Context<CreateGasTank>,
+        reimbursement_per_payment: u64,
+    ) -> Result<()> {
+        instructions::gas_tank::create_gas_tank_handler(ctx, reimbursement_per_payment)
+    }
+
+    /// Deposit lamports into a merchant's gas tank
+    pub fn deposit_gas_tank(ctx: Context<DepositGasTank>, amount: u64) -> Result<()> {
+        instructions::gas_tank::deposit_gas_tank_handler(ctx, amount)
+    }
+
+    /// Withdraw lamports from the gas tank (rent-exempt minimum stays)
+    pub fn withdraw_gas_tank(ctx: Context<WithdrawGasTank>, amount: u64) -> Result<()> {
+        instructions::gas_tank::withdraw_gas_tank_handler(ctx, amount)
+    }
+
+    /// Set lamports reimbursed to the payer per payment (bounded)
+    pub fn set_gas_tank_reimbursement(
+        ctx: Context<SetGasTankReimbursement>,
+        reimbursement_per_payment: u64,
+    ) -> Result<()> {
+        instructions::gas_tank::set_gas_tank_reimbursement_handler(ctx, reimbursement_per_payment)
+    }
+
     // ============================================
     // Initialize
     // ============================================
@@ -80,20 +209,25 @@ pub mod setto_payment {
     }
 
     // ============================================
-    // Emergency Admin Functions
+    // Emergency Admin Functions (or Pauser / Signer Manager roles)
     // ============================================
 
-    /// Pause the program (emergency stop)
-    pub fn pause(ctx: Context<Pause>) -> Result<()> {
-        instructions::admin::pause_handler(ctx)
+    /// Pause payment kinds / settlement, a mint or a server signer (emergency stop)
+    pub fn pause(ctx: Context<Pause>, scope: PauseScope) -> Result<()> {
+        instructions::admin::pause_handler(ctx, scope)
+    }
+
+    /// Unpause payment kinds / settlement, a mint or a server signer
+    pub fn unpause(ctx: Context<Unpause>, scope: PauseScope) -> Result<()> {
+        instructions::admin::unpause_handler(ctx, scope)
     }
 
-    /// Unpause the program
-    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
-        instructions::admin::unpause_handler(ctx)
+    /// Temporarily deactivate a server signer (incident response; keeps the PDA)
+    pub fn deactivate_server_signer(ctx: Context<DeactivateServerSigner>) -> Result<()> {
+        instructions::admin::deactivate_server_signer_handler(ctx)
     }
 
-    /// Emergency add server signer (when key rotation needed urgently)
+    /// Emergency add server signer (urgent key rotation; only while everything is paused)
     pub fn emergency_add_server_signer(ctx: Context<EmergencyAddServerSigner>) -> Result<()> {
         instructions::admin::emergency_add_server_signer_handler(ctx)
     }
@@ -103,18 +237,89 @@ pub mod setto_payment {
         instructions::admin::emergency_remove_server_signer_handler(ctx)
     }
 
+    // ============================================
+    // Timelock (schedule: Authority, cancel: Authority or Emergency Admin)
+    // ============================================
+
+    /// Queue a server signer addition, emergency admin change, authority transfer
+    /// or timelock delay change; ETA must be at least Config.timelock_delay away
+    pub fn schedule_action(
+        ctx: Context<ScheduleAction>,
+        params: ScheduleActionParams,
+    ) -> Result<()> {
+        instructions::timelock::schedule_action_handler(ctx, params)
+    }
+
+    /// Execute a queued action after its ETA (anyone)
+    pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+        instructions::timelock::execute_action_handler(ctx)
+    }
+
+    /// Cancel a queued action
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        instructions::timelock::cancel_action_handler(ctx)
+    }
+
+    // ============================================
+    // Council (built-in M-of-N multisig for Config.authority)
+    // ============================================
+
+    /// Create the council (Config.authority only); point Config.authority at the
+    /// council authority PDA afterwards to hand over administration
+    pub fn create_council(ctx: Context<CreateCouncil>, params: CouncilMembersParams) -> Result<()> {
+        instructions::council::create_council_handler(ctx, params)
+    }
+
+    /// Replace council members and threshold (council authority only, via proposal)
+    pub fn set_council_members(
+        ctx: Context<SetCouncilMembers>,
+        params: CouncilMembersParams,
+    ) -> Result<()> {
+        instructions::council::set_council_members_handler(ctx, params)
+    }
+
+    /// Propose an instruction of this program, signed by the council authority on execution
+    pub fn create_council_proposal(
+        ctx: Context<CreateCouncilProposal>,
+        params: CouncilProposalParams,
+    ) -> Result<()> {
+        instructions::council::create_council_proposal_handler(ctx, params)
+    }
+
+    /// Approve a proposal (council member)
+    pub fn approve_council_proposal(ctx: Context<ApproveCouncilProposal>) -> Result<()> {
+        instructions::council::approve_council_proposal_handler(ctx)
+    }
+
+    /// Execute a proposal once approvals reach the threshold (anyone)
+    pub fn execute_council_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteCouncilProposal<'info>>,
+    ) -> Result<()> {
+        instructions::council::execute_council_proposal_handler(ctx)
+    }
+
+    /// Cancel a proposal (proposer)
+    pub fn cancel_council_proposal(ctx: Context<CancelCouncilProposal>) -> Result<()> {
+        instructions::council::cancel_council_proposal_handler(ctx)
+    }
+
     // ============================================
     // Authority Functions
     // ============================================
 
-    /// Update emergency admin address
-    pub fn set_emergency_admin(ctx: Context<SetEmergencyAdmin>) -> Result<()> {
-        instructions::admin::set_emergency_admin_handler(ctx)
+    /// Accept the emergency admin role (signed by the pending emergency admin)
+    pub fn accept_emergency_admin(ctx: Context<AcceptEmergencyAdmin>) -> Result<()> {
+        instructions::admin::accept_emergency_admin_handler(ctx)
+    }
+
+    /// Cancel a pending emergency admin change
+    pub fn cancel_emergency_admin_change(ctx: Context<CancelEmergencyAdminChange>) -> Result<()> {
+        instructions::admin::cancel_emergency_admin_change_handler(ctx)
     }
 
-    /// Add a new server signer
-    pub fn add_server_signer(ctx: Context<AddServerSigner>) -> Result<()> {
-        instructions::admin::add_server_signer_handler(ctx)
+    /// Reactivate a deactivated server signer
+    pub fn reactivate_server_signer(ctx: Context<ReactivateServerSigner>) -> Result<()> {
+        instructions::admin::reactivate_server_signer_handler(ctx)
     }
 
     /// Remove a server signer
@@ -122,8 +327,116 @@ pub mod setto_payment {
         instructions::admin::remove_server_signer_handler(ctx)
     }
 
-    /// Transfer authority to new address
-    pub fn transfer_authority(ctx: Context<TransferAuthority>) -> Result<()> {
-        instructions::admin::transfer_authority_handler(ctx)
+    /// Accept authority (signed by the pending authority)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::admin::accept_authority_handler(ctx)
+    }
+
+    /// Cancel a pending authority transfer
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::admin::cancel_authority_transfer_handler(ctx)
+    }
+
+    /// Select the memo encoding (legacy text or versioned binary, see memo module)
+    pub fn set_memo_format(ctx: Context<SetMemoFormat>, memo_format: MemoFormat) -> Result<()> {
+        instructions::admin::set_memo_format_handler(ctx, memo_format)
+    }
+
+    /// Require direct payments to go to registered, active merchants
+    pub fn set_merchant_registry_required(
+        ctx: Context<SetMerchantRegistryConfig>,
+        required: bool,
+    ) -> Result<()> {
+        instructions::merchant_admin::set_merchant_registry_required_handler(ctx, required)
+    }
+
+    // ============================================
+    // Signer Registry (Authority Only)
+    // ============================================
+
+    /// Create the signer registry for an existing deployment
+    /// (remaining_accounts: existing ServerSigner PDAs)
+    pub fn create_signer_registry<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSignerRegistry<'info>>,
+        max_signers: u8,
+    ) -> Result<()> {
+        instructions::signer_registry::create_signer_registry_handler(ctx, max_signers)
+    }
+
+    /// Set the maximum number of registered server signers
+    pub fn set_max_server_signers(
+        ctx: Context<SetMaxServerSigners>,
+        max_signers: u8,
+    ) -> Result<()> {
+        instructions::signer_registry::set_max_server_signers_handler(ctx, max_signers)
+    }
+
+    // ============================================
+    // Account Migration (Authority Only)
+    // ============================================
+
+    /// Upgrade a pre-versioning Config in place (realloc + defaults for new fields)
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate::migrate_config_handler(ctx)
+    }
+
+    /// Upgrade an older ServerSigner layout in place (Config must be migrated first)
+    pub fn migrate_server_signer(ctx: Context<MigrateServerSigner>) -> Result<()> {
+        instructions::migrate::migrate_server_signer_handler(ctx)
+    }
+
+    /// Upgrade the pre-versioning Delegate in place (Config must be migrated first)
+    pub fn migrate_delegate(ctx: Context<MigrateDelegate>) -> Result<()> {
+        instructions::migrate::migrate_delegate_handler(ctx)
+    }
+
+    // ============================================
+    // Roles (Authority Only)
+    // ============================================
+
+    /// Grant a delegated admin role (pauser, signer/fee/merchant manager, pool operator)
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        instructions::role_admin::grant_role_handler(ctx, role)
+    }
+
+    /// Revoke a delegated admin role
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::role_admin::revoke_role_handler(ctx)
+    }
+
+    // ============================================
+    // Fee Config (Authority or Fee Manager)
+    // ============================================
+
+    /// Create or update the global fee config (fee wallet, bps, min/max fee)
+    pub fn set_fee_config(ctx: Context<SetFeeConfig>, params: FeeConfigParams) -> Result<()> {
+        instructions::fee_admin::set_fee_config_handler(ctx, params)
+    }
+
+    /// Create or update a per-mint fee override
+    pub fn set_mint_fee_config(
+        ctx: Context<SetMintFeeConfig>,
+        params: FeeConfigParams,
+    ) -> Result<()> {
+        instructions::fee_admin::set_mint_fee_config_handler(ctx, params)
+    }
+
+    /// Remove a per-mint fee override (mint falls back to the global fee config)
+    pub fn remove_mint_fee_config(ctx: Context<RemoveMintFeeConfig>) -> Result<()> {
+        instructions::fee_admin::remove_mint_fee_config_handler(ctx)
+    }
+
+    // ============================================
+    // Mint Allowlist (Authority Only)
+    // ============================================
+
+    /// Onboard or update a mint (enabled flag, amount limits, allowed payment kinds)
+    pub fn set_mint_config(ctx: Context<SetMintConfig>, params: MintConfigParams) -> Result<()> {
+        instructions::mint_admin::set_mint_config_handler(ctx, params)
+    }
+
+    /// Remove a mint from the allowlist (payments in the mint are rejected)
+    pub fn remove_mint_config(ctx: Context<RemoveMintConfig>) -> Result<()> {
+        instructions::mint_admin::remove_mint_config_handler(ctx)
     }
 }
@@ -0,0 +1,393 @@
+//! Payment memo encoding.
+//!
+//! Every payment, settlement and refund writes a memo through the Memo Program.
+//! Two encodings exist, selected by `Config.memo_format`:
+//!
+//! - `MemoFormat::Text` (legacy): pipe-delimited text, e.g.
+//!   `DIRECT_PAYMENT|<payment_id hex>|<sender>|...` — see [`SettoMemo::to_text`]
+//! - `MemoFormat::Binary`: `SETTO1:` followed by standard base64 (with padding) of
+//!   the borsh-serialized [`SettoMemo`] — see [`SettoMemo::encode`] / [`SettoMemo::decode`]
+//!
+//! The `1` in the prefix is the format version; any layout change gets a new prefix.
+//!
+//! Borsh layout of `SettoMemo` (all integers little-endian):
+//! - variant tag (u8): 0 = DirectPayment, 1 = PoolPayment, 2 = PoolSettlement, 3 = PoolRefund
+//! - followed by the variant's fields in declaration order; `Vec<T>` is a u32
+//!   length followed by the elements, enums are a u8 tag
+
+use anchor_lang::prelude::*;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+use crate::instructions::TransferFeeMode;
+use crate::state::{AddressFormat, MemoFormat};
+
+/// Prefix of binary (version 1) memos
+pub const BINARY_MEMO_PREFIX: &str = "SETTO1:";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SettoMemo {
+    DirectPayment(DirectPaymentMemo),
+    PoolPayment(PoolPaymentMemo),
+    PoolSettlement(PoolSettlementMemo),
+    PoolRefund(PoolRefundMemo),
+}
+
+/// Text: `DIRECT_PAYMENT|payment_id|sender|recipient|total_amount|amount|protocol_fee|
+/// relayer|relayer_fee|transfer_fee_mode|transfer_fee_withheld|reference|reference_keys`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DirectPaymentMemo {
+    /// [u8; 32] (text: hex)
+    pub payment_id: [u8; 32],
+    pub sender: Pubkey,
+    /// Owner of the recipient token account
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub amount: u64,
+    pub protocol_fee: u64,
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+    /// Text: 0 = Net, 1 = GrossUp
+    pub transfer_fee_mode: TransferFeeMode,
+    /// Token-2022 transfer fee withheld across all legs
+    pub transfer_fee_withheld: u64,
+    /// Merchant order reference, ≤ 64 bytes (text: hex)
+    pub reference: Vec<u8>,
+    /// Solana Pay reference keys, ≤ 4 (text: comma-separated)
+    pub reference_keys: Vec<Pubkey>,
+}
+
+/// Text: `POOL_PAYMENT|payment_id|sender|pool|recipient|total_amount|amount|service_fee|
+/// destination_chain_id|address_format|relayer|relayer_fee|transfer_fee_mode|
+/// transfer_fee_withheld|credited_amount|reference|reference_keys`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolPaymentMemo {
+    /// [u8; 32] (text: hex)
+    pub payment_id: [u8; 32],
+    pub sender: Pubkey,
+    pub pool: Pubkey,
+    /// Tracked EVM/SVM recipient (text: hex)
+    pub recipient: [u8; 32],
+    pub total_amount: u64,
+    pub amount: u64,
+    pub service_fee: u64,
+    pub destination_chain_id: u64,
+    /// Text: 0 = EVM, 1 = SVM
+    pub address_format: AddressFormat,
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+    /// Text: 0 = Net, 1 = GrossUp
+    pub transfer_fee_mode: TransferFeeMode,
+    /// Token-2022 transfer fee withheld across all legs
+    pub transfer_fee_withheld: u64,
+    /// Amount credited to the recipient's ledger entry
+    pub credited_amount: u64,
+    /// Merchant order reference, ≤ 64 bytes (text: hex)
+    pub reference: Vec<u8>,
+    /// Solana Pay reference keys, ≤ 4 (text: comma-separated)
+    pub reference_keys: Vec<Pubkey>,
+}
+
+/// Text: `POOL_SETTLEMENT|pool|recipient|destination_chain_id|address_format|destination|
+/// amount|transfer_fee_mode|transfer_fee_withheld|settlement_nonce`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolSettlementMemo {
+    pub pool: Pubkey,
+    /// Tracked EVM/SVM recipient (text: hex)
+    pub recipient: [u8; 32],
+    pub destination_chain_id: u64,
+    /// Text: 0 = EVM, 1 = SVM
+    pub address_format: AddressFormat,
+    /// Owner of the destination token account
+    pub destination: Pubkey,
+    /// Debited from the ledger
+    pub amount: u64,
+    /// Text: 0 = Net, 1 = GrossUp
+    pub transfer_fee_mode: TransferFeeMode,
+    pub transfer_fee_withheld: u64,
+    pub settlement_nonce: u64,
+}
+
+/// Text: `POOL_REFUND|payment_id|pool|sender|amount|transfer_fee_mode|transfer_fee_withheld`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolRefundMemo {
+    /// [u8; 32] (text: hex)
+    pub payment_id: [u8; 32],
+    pub pool: Pubkey,
+    pub sender: Pubkey,
+    /// amount + service fee returned from the vault
+    pub amount: u64,
+    /// Text: 0 = Net, 1 = GrossUp
+    pub transfer_fee_mode: TransferFeeMode,
+    pub transfer_fee_withheld: u64,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn join_keys(keys: &[Pubkey]) -> String {
+    keys.iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl SettoMemo {
+    /// Render the memo in the configured format
+    pub fn render(&self, format: MemoFormat) -> String {
+        match format {
+            MemoFormat::Text => self.to_text(),
+            MemoFormat::Binary => self.encode(),
+        }
+    }
+
+    /// Binary format: `SETTO1:` + base64(borsh(self))
+    pub fn encode(&self) -> String {
+        let mut data = Vec::new();
+        // Writing into a Vec cannot fail
+        self.serialize(&mut data).unwrap();
+        format!("{}{}", BINARY_MEMO_PREFIX, STANDARD.encode(data))
+    }
+
+    /// Decode a binary memo; None for legacy text memos or malformed input
+    pub fn decode(memo: &str) -> Option<Self> {
+        let data = STANDARD
+            .decode(memo.strip_prefix(BINARY_MEMO_PREFIX)?)
+            .ok()?;
+        Self::try_from_slice(&data).ok()
+    }
+
+    /// Legacy pipe-delimited text format
+    pub fn to_text(&self) -> String {
+        match self {
+            SettoMemo::DirectPayment(memo) => format!(
+                "DIRECT_PAYMENT|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+                to_hex(&memo.payment_id),
+                memo.sender,
+                memo.recipient,
+                memo.total_amount,
+                memo.amount,
+                memo.protocol_fee,
+                memo.relayer,
+                memo.relayer_fee,
+                memo.transfer_fee_mode as u8,
+                memo.transfer_fee_withheld,
+                to_hex(&memo.reference),
+                join_keys(&memo.reference_keys),
+            ),
+            SettoMemo::PoolPayment(memo) => format!(
+                "POOL_PAYMENT|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+                to_hex(&memo.payment_id),
+                memo.sender,
+                memo.pool,
+                to_hex(&memo.recipient),
+                memo.total_amount,
+                memo.amount,
+                memo.service_fee,
+                memo.destination_chain_id,
+                memo.address_format as u8,
+                memo.relayer,
+                memo.relayer_fee,
+                memo.transfer_fee_mode as u8,
+                memo.transfer_fee_withheld,
+                memo.credited_amount,
+                to_hex(&memo.reference),
+                join_keys(&memo.reference_keys),
+            ),
+            SettoMemo::PoolSettlement(memo) => format!(
+                "POOL_SETTLEMENT|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+                memo.pool,
+                to_hex(&memo.recipient),
+                memo.destination_chain_id,
+                memo.address_format as u8,
+                memo.destination,
+                memo.amount,
+                memo.transfer_fee_mode as u8,
+                memo.transfer_fee_withheld,
+                memo.settlement_nonce,
+            ),
+            SettoMemo::PoolRefund(memo) => format!(
+                "POOL_REFUND|{}|{}|{}|{}|{}|{}",
+                to_hex(&memo.payment_id),
+                memo.pool,
+                memo.sender,
+                memo.amount,
+                memo.transfer_fee_mode as u8,
+                memo.transfer_fee_withheld,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn direct_payment() -> SettoMemo {
+        SettoMemo::DirectPayment(DirectPaymentMemo {
+            payment_id: [1; 32],
+            sender: key(2),
+            recipient: key(3),
+            total_amount: 1_010_500,
+            amount: 1_000_000,
+            protocol_fee: 10_000,
+            relayer: key(4),
+            relayer_fee: 500,
+            transfer_fee_mode: TransferFeeMode::GrossUp,
+            transfer_fee_withheld: 25,
+            reference: b"order-42".to_vec(),
+            reference_keys: vec![key(5), key(6)],
+        })
+    }
+
+    fn pool_payment() -> SettoMemo {
+        SettoMemo::PoolPayment(PoolPaymentMemo {
+            payment_id: [7; 32],
+            sender: key(8),
+            pool: key(9),
+            recipient: [10; 32],
+            total_amount: 2_020_000,
+            amount: 2_000_000,
+            service_fee: 20_000,
+            destination_chain_id: 8453,
+            address_format: AddressFormat::Evm,
+            relayer: Pubkey::default(),
+            relayer_fee: 0,
+            transfer_fee_mode: TransferFeeMode::Net,
+            transfer_fee_withheld: 40,
+            credited_amount: 1_999_960,
+            reference: Vec::new(),
+            reference_keys: vec![key(11)],
+        })
+    }
+
+    fn pool_settlement() -> SettoMemo {
+        SettoMemo::PoolSettlement(PoolSettlementMemo {
+            pool: key(12),
+            recipient: [13; 32],
+            destination_chain_id: 101,
+            address_format: AddressFormat::Svm,
+            destination: key(14),
+            amount: 5_000_000,
+            transfer_fee_mode: TransferFeeMode::GrossUp,
+            transfer_fee_withheld: 100,
+            settlement_nonce: 3,
+        })
+    }
+
+    fn pool_refund() -> SettoMemo {
+        SettoMemo::PoolRefund(PoolRefundMemo {
+            payment_id: [15; 32],
+            pool: key(16),
+            sender: key(17),
+            amount: 2_020_000,
+            transfer_fee_mode: TransferFeeMode::Net,
+            transfer_fee_withheld: 0,
+        })
+    }
+
+    #[test]
+    fn binary_round_trips_every_variant() {
+        for memo in [
+            direct_payment(),
+            pool_payment(),
+            pool_settlement(),
+            pool_refund(),
+        ] {
+            let encoded = memo.encode();
+            assert!(encoded.starts_with(BINARY_MEMO_PREFIX));
+            assert_eq!(SettoMemo::decode(&encoded), Some(memo));
+        }
+    }
+
+    #[test]
+    fn binary_variant_tags_are_stable() {
+        for (memo, tag) in [
+            (direct_payment(), 0),
+            (pool_payment(), 1),
+            (pool_settlement(), 2),
+            (pool_refund(), 3),
+        ] {
+            let data = STANDARD
+                .decode(memo.encode().strip_prefix(BINARY_MEMO_PREFIX).unwrap())
+                .unwrap();
+            assert_eq!(data[0], tag);
+        }
+    }
+
+    #[test]
+    fn decode_rejects_wrong_prefix_and_truncated_input() {
+        let encoded = direct_payment().encode();
+        let payload = encoded.strip_prefix(BINARY_MEMO_PREFIX).unwrap();
+
+        // Other format versions and legacy text memos
+        assert_eq!(SettoMemo::decode(&format!("SETTO2:{}", payload)), None);
+        assert_eq!(SettoMemo::decode(payload), None);
+        assert_eq!(SettoMemo::decode(&direct_payment().to_text()), None);
+
+        // Truncated base64 and truncated borsh (valid base64 of a cut payload)
+        assert_eq!(SettoMemo::decode(&encoded[..encoded.len() - 3]), None);
+        let mut data = STANDARD.decode(payload).unwrap();
+        data.pop();
+        assert_eq!(
+            SettoMemo::decode(&format!("{}{}", BINARY_MEMO_PREFIX, STANDARD.encode(data))),
+            None
+        );
+        assert_eq!(SettoMemo::decode(BINARY_MEMO_PREFIX), None);
+    }
+
+    #[test]
+    fn text_keeps_baseline_field_order() {
+        // DIRECT_PAYMENT|payment_id|sender|recipient|total_amount|amount|protocol_fee|...
+        let text = direct_payment().to_text();
+        let fields: Vec<&str> = text.split('|').collect();
+        assert_eq!(
+            fields[..7],
+            [
+                "DIRECT_PAYMENT",
+                &to_hex(&[1; 32]),
+                &key(2).to_string(),
+                &key(3).to_string(),
+                "1010500",
+                "1000000",
+                "10000",
+            ]
+        );
+        assert_eq!(fields.len(), 13);
+        assert_eq!(
+            fields[12],
+            format!("{},{}", key(5), key(6)),
+            "reference keys stay last"
+        );
+
+        // POOL_PAYMENT|payment_id|sender|pool|recipient|total_amount|amount|service_fee|...
+        let text = pool_payment().to_text();
+        let fields: Vec<&str> = text.split('|').collect();
+        assert_eq!(
+            fields[..8],
+            [
+                "POOL_PAYMENT",
+                &to_hex(&[7; 32]),
+                &key(8).to_string(),
+                &key(9).to_string(),
+                &to_hex(&[10; 32]),
+                "2020000",
+                "2000000",
+                "20000",
+            ]
+        );
+        assert_eq!(fields.len(), 17);
+    }
+
+    #[test]
+    fn render_follows_config_format() {
+        let memo = pool_refund();
+        assert_eq!(memo.render(MemoFormat::Text), memo.to_text());
+        assert_eq!(memo.render(MemoFormat::Binary), memo.encode());
+        assert!(memo.to_text().starts_with("POOL_REFUND|"));
+    }
+}
@@ -5,18 +5,84 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
-    /// Authority that can update config (master wallet / multisig)
-    /// Can: add/remove_server_signer, transfer-authority, set-emergency-admin
+    /// Layout version (Config::CURRENT_VERSION), upgraded by migrate_config
+    pub version: u8,
+    /// Authority that can update config (master wallet / multisig / council authority PDA)
+    /// Can: grant/revoke roles, schedule timelocked actions (add server signer,
+    ///      emergency admin change, authority transfer), plus everything delegated
+    ///      to roles (see RoleAssignment)
     pub authority: Pubkey,
     /// Emergency admin for pause/unpause (operational wallet)
-    /// Can: pause, unpause, emergency_add/remove_server_signer
+    /// Can: pause, unpause, emergency_add (while paused) / emergency_remove_server_signer
     pub emergency_admin: Pubkey,
-    /// Emergency pause flag
-    pub paused: bool,
+    /// Proposed authority awaiting acceptance (Pubkey::default() if none)
+    pub pending_authority: Pubkey,
+    /// Proposed emergency admin awaiting acceptance (Pubkey::default() if none)
+    pub pending_emergency_admin: Pubkey,
+    /// Paused operations, bitmask of PAUSE_* flags
+    pub pause_flags: u8,
+    /// Direct payments only allowed to registered, active merchants
+    pub merchant_registry_required: bool,
+    /// Encoding of payment / settlement memos
+    pub memo_format: MemoFormat,
+    /// Minimum seconds between scheduling and executing a PendingAction
+    pub timelock_delay: i64,
+    /// Number of PendingActions ever scheduled (next action id)
+    pub action_count: u64,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Reserved for future fields (new fields take bytes from here)
+    pub reserved: [u8; 64],
 }
 
 impl Config {
     pub const SEED: &'static [u8] = b"config";
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// Direct payments (sender signs)
+    pub const PAUSE_DIRECT: u8 = 1 << 0;
+    /// Direct payments via Delegate PDA
+    pub const PAUSE_DIRECT_DELEGATED: u8 = 1 << 1;
+    /// Pool payments (sender signs)
+    pub const PAUSE_POOL: u8 = 1 << 2;
+    /// Pool payments via Delegate PDA
+    pub const PAUSE_POOL_DELEGATED: u8 = 1 << 3;
+    /// Pool settlements and refunds
+    pub const PAUSE_SETTLEMENT: u8 = 1 << 4;
+    pub const PAUSE_ALL: u8 = Self::PAUSE_DIRECT
+        | Self::PAUSE_DIRECT_DELEGATED
+        | Self::PAUSE_POOL
+        | Self::PAUSE_POOL_DELEGATED
+        | Self::PAUSE_SETTLEMENT;
+    /// Timelock delay set at initialization (24 hours)
+    pub const DEFAULT_TIMELOCK_DELAY: i64 = 24 * 60 * 60;
+    /// Upper bound for timelock_delay (30 days)
+    pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
+
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+}
+
+/// Pre-versioning Config layout (version 0), read by migrate_config
+#[derive(AnchorDeserialize)]
+pub struct LegacyConfig {
+    pub authority: Pubkey,
+    pub emergency_admin: Pubkey,
+    pub paused: bool,
+    pub bump: u8,
+}
+
+impl LegacyConfig {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1;
+}
+
+/// Memo encoding (see crate::memo)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MemoFormat {
+    /// Legacy pipe-delimited text (`DIRECT_PAYMENT|...`)
+    Text,
+    /// `SETTO1:` + base64(borsh(SettoMemo))
+    Binary,
 }
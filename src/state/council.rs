@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Built-in M-of-N multisig
+/// Config.authority may be set to the council authority PDA (["council_authority"]),
+/// which signs admin instructions executed from approved CouncilProposals
+/// Seeds: ["council"]
+#[account]
+#[derive(InitSpace)]
+pub struct Council {
+    /// Member wallets that can propose and approve
+    #[max_len(10)]
+    pub members: Vec<Pubkey>,
+    /// Approvals required to execute a proposal
+    pub threshold: u8,
+    /// Number of proposals ever created (next proposal id)
+    pub proposal_count: u64,
+    /// Bump seed of the council authority PDA
+    pub authority_bump: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Council {
+    pub const SEED: &'static [u8] = b"council";
+    /// Data-less PDA that signs executed proposals (and pays rent for them)
+    pub const AUTHORITY_SEED: &'static [u8] = b"council_authority";
+    pub const MAX_MEMBERS: usize = 10;
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+/// Pending instruction of this program, executed via CPI signed by the council authority
+/// Seeds: ["proposal", id (u64 LE)]
+#[account]
+#[derive(InitSpace)]
+pub struct CouncilProposal {
+    /// Sequential id (Council.proposal_count at creation time)
+    pub id: u64,
+    /// Member that created the proposal (receives rent back)
+    pub proposer: Pubkey,
+    /// Accounts of the instruction, in order
+    #[max_len(16)]
+    pub accounts: Vec<ProposalAccount>,
+    /// Instruction data (Anchor discriminator + borsh args)
+    #[max_len(256)]
+    pub data: Vec<u8>,
+    /// Members that approved; only current members count towards the threshold
+    #[max_len(10)]
+    pub approvals: Vec<Pubkey>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CouncilProposal {
+    pub const SEED: &'static [u8] = b"proposal";
+    pub const MAX_ACCOUNTS: usize = 16;
+    pub const MAX_DATA_LEN: usize = 256;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
@@ -6,10 +6,26 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct Delegate {
+    /// Layout version (Delegate::CURRENT_VERSION), upgraded by migrate_delegate
+    pub version: u8,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Reserved for future fields (new fields take bytes from here)
+    pub reserved: [u8; 32],
 }
 
 impl Delegate {
     pub const SEED: &'static [u8] = b"delegate";
+    pub const CURRENT_VERSION: u8 = 1;
+}
+
+/// Pre-versioning Delegate layout (version 0), read by migrate_delegate
+#[derive(AnchorDeserialize)]
+pub struct LegacyDelegate {
+    pub bump: u8,
+}
+
+impl LegacyDelegate {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 1;
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Record of a single direct payment, created by the direct payment instructions
+/// Required when the payment is reimbursed from a gas tank, optional otherwise (the payer
+/// funds its rent); prevents a signed payment and its reimbursement from being replayed
+/// Seeds: ["direct_payment", payment_id]
+#[account]
+#[derive(InitSpace)]
+pub struct DirectPaymentRecord {
+    pub payment_id: [u8; 32],
+    /// Original sender (token owner)
+    pub sender: Pubkey,
+    /// Slot the payment was processed in
+    pub slot: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl DirectPaymentRecord {
+    pub const SEED: &'static [u8] = b"direct_payment";
+}
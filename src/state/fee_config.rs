@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+/// Protocol fee configuration, managed by Config.authority
+/// Direct payments must use exactly this fee wallet and fee
+/// Global seeds: ["fee_config"] (mint = Pubkey::default())
+/// Per-mint override seeds: ["fee_config", mint]
+#[account]
+#[derive(InitSpace)]
+pub struct FeeConfig {
+    /// Mint this override applies to (Pubkey::default() for the global config)
+    pub mint: Pubkey,
+    /// Wallet receiving protocol fees (owner of the fee token account)
+    pub fee_wallet: Pubkey,
+    /// Protocol fee in basis points of `amount`
+    pub fee_bps: u16,
+    /// Lower bound applied to the bps fee
+    pub min_fee: u64,
+    /// Upper bound applied to the bps fee
+    pub max_fee: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl FeeConfig {
+    pub const SEED: &'static [u8] = b"fee_config";
+    pub const MAX_FEE_BPS: u16 = 10_000;
+
+    /// Protocol fee for a payment of `amount`:
+    /// clamp(amount * fee_bps / 10_000, min_fee, max_fee)
+    pub fn compute_fee(&self, amount: u64) -> u64 {
+        let bps_fee = (amount as u128 * self.fee_bps as u128 / Self::MAX_FEE_BPS as u128) as u64;
+        bps_fee.clamp(self.min_fee, self.max_fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_config(fee_bps: u16, min_fee: u64, max_fee: u64) -> FeeConfig {
+        FeeConfig {
+            mint: Pubkey::default(),
+            fee_wallet: Pubkey::default(),
+            fee_bps,
+            min_fee,
+            max_fee,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn bps_fee_rounds_down() {
+        let config = fee_config(30, 0, u64::MAX);
+        assert_eq!(config.compute_fee(1_000_000), 3_000);
+        // 333 * 30 / 10_000 = 0.999
+        assert_eq!(config.compute_fee(333), 0);
+        // 3_334 * 30 / 10_000 = 10.002
+        assert_eq!(config.compute_fee(3_334), 10);
+        assert_eq!(config.compute_fee(0), 0);
+    }
+
+    #[test]
+    fn fee_is_clamped_to_min_and_max() {
+        let config = fee_config(100, 50, 1_000);
+        // 1% of 1_000 = 10 → raised to min_fee
+        assert_eq!(config.compute_fee(1_000), 50);
+        // 1% of 20_000 = 200 → within bounds
+        assert_eq!(config.compute_fee(20_000), 200);
+        // 1% of 1_000_000 = 10_000 → capped at max_fee
+        assert_eq!(config.compute_fee(1_000_000), 1_000);
+        // Zero bps still charges min_fee
+        assert_eq!(fee_config(0, 7, 7).compute_fee(1_000_000), 7);
+    }
+
+    #[test]
+    fn max_amount_does_not_overflow() {
+        let full = fee_config(FeeConfig::MAX_FEE_BPS, 0, u64::MAX);
+        assert_eq!(full.compute_fee(u64::MAX), u64::MAX);
+
+        let half = fee_config(5_000, 0, u64::MAX);
+        assert_eq!(half.compute_fee(u64::MAX), u64::MAX / 2);
+
+        let capped = fee_config(25, 0, 1_000_000);
+        assert_eq!(capped.compute_fee(u64::MAX), 1_000_000);
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Merchant-funded SOL gas tank
+/// Reimburses the payer of a direct payment to the merchant
+/// Holds deposited lamports on top of its rent-exempt minimum
+/// Seeds: ["gas_tank", merchant_owner]
+#[account]
+#[derive(InitSpace)]
+pub struct GasTank {
+    /// Merchant wallet (Merchant.owner) — can withdraw and set the reimbursement
+    pub merchant: Pubkey,
+    /// Cap on the lamports reimbursed per payment (the signed gas_reimbursement is paid)
+    pub reimbursement_per_payment: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GasTank {
+    pub const SEED: &'static [u8] = b"gas_tank";
+    /// Upper bound for reimbursement_per_payment (0.01 SOL)
+    pub const MAX_REIMBURSEMENT_PER_PAYMENT: u64 = 10_000_000;
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// Registered merchant, managed by the authority or the merchant manager
+/// When Config.merchant_registry_required is set, direct payments may only
+/// pay into a settlement account of an active merchant
+/// Seeds: ["merchant", owner]
+#[account]
+#[derive(InitSpace)]
+pub struct Merchant {
+    /// Merchant's wallet
+    pub owner: Pubkey,
+    /// Settlement token account per mint (direct payment destinations)
+    #[max_len(8)]
+    pub settlement_accounts: Vec<SettlementAccount>,
+    /// Fee tier (off-chain fee schedule; on-chain cap is FeeConfig)
+    pub fee_tier: u8,
+    pub status: MerchantStatus,
+    /// Display name
+    #[max_len(32)]
+    pub name: String,
+    /// Metadata URI
+    #[max_len(128)]
+    pub uri: String,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Merchant {
+    pub const SEED: &'static [u8] = b"merchant";
+    pub const MAX_SETTLEMENT_ACCOUNTS: usize = 8;
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_URI_LEN: usize = 128;
+
+    /// Settlement token account registered for `mint`, if any
+    pub fn settlement_account(&self, mint: &Pubkey) -> Option<Pubkey> {
+        self.settlement_accounts
+            .iter()
+            .find(|s| s.mint == *mint)
+            .map(|s| s.token_account)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SettlementAccount {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MerchantStatus {
+    Active,
+    /// Direct payments to this merchant are rejected
+    Suspended,
+}
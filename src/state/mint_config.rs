@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// Mint allowlist entry, managed by Config.authority
+/// Payments in a mint are rejected unless its MintConfig exists and is enabled
+/// Seeds: ["mint", mint]
+#[account]
+#[derive(InitSpace)]
+pub struct MintConfig {
+    /// Token mint this entry applies to
+    pub mint: Pubkey,
+    /// Disabled mints reject all payments (e.g. a depegged stablecoin)
+    pub enabled: bool,
+    /// Temporarily paused by the emergency admin / Pauser (independent of `enabled`)
+    pub paused: bool,
+    /// Minimum payment `amount` (inclusive)
+    pub min_amount: u64,
+    /// Maximum payment `amount` (inclusive)
+    pub max_amount: u64,
+    /// Bitmask of allowed payment kinds (KIND_DIRECT | KIND_POOL)
+    pub allowed_kinds: u8,
+    /// Whether Delegate PDA pulls (gasless, user doesn't sign) are allowed
+    pub allow_delegated: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MintConfig {
+    pub const SEED: &'static [u8] = b"mint";
+
+    /// Direct payments (sender → recipient)
+    pub const KIND_DIRECT: u8 = 1 << 0;
+    /// Pool payments (sender → pool vault)
+    pub const KIND_POOL: u8 = 1 << 1;
+    pub const ALL_KINDS: u8 = Self::KIND_DIRECT | Self::KIND_POOL;
+}
@@ -1,7 +1,31 @@
 pub mod config;
+pub mod council;
 pub mod delegate;
+pub mod direct_payment_record;
+pub mod fee_config;
+pub mod gas_tank;
+pub mod merchant;
+pub mod mint_config;
+pub mod pending_action;
+pub mod pool;
+pub mod pool_ledger;
+pub mod pool_payment_record;
+pub mod role;
 pub mod server_signer;
+pub mod signer_registry;
 
 pub use config::*;
+pub use council::*;
 pub use delegate::*;
+pub use direct_payment_record::*;
+pub use fee_config::*;
+pub use gas_tank::*;
+pub use merchant::*;
+pub use mint_config::*;
+pub use pending_action::*;
+pub use pool::*;
+pub use pool_ledger::*;
+pub use pool_payment_record::*;
+pub use role::*;
 pub use server_signer::*;
+pub use signer_registry::*;
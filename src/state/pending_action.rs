@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Sensitive admin action queued by Config.authority behind Config.timelock_delay
+/// Executable by anyone once `eta` has passed, cancellable by authority or emergency admin
+/// Seeds: ["action", id (u64 LE)]
+#[account]
+#[derive(InitSpace)]
+pub struct PendingAction {
+    /// Sequential id (Config.action_count at scheduling time)
+    pub id: u64,
+    /// What the action does
+    pub kind: AdminActionKind,
+    /// Server signer / emergency admin / authority the action applies to
+    /// Pubkey::default() for SetTimelockDelay
+    pub target: Pubkey,
+    /// New timelock delay in seconds (SetTimelockDelay only)
+    pub delay: i64,
+    /// Earliest unix timestamp the action can be executed
+    pub eta: i64,
+    /// Authority that scheduled the action (receives rent back)
+    pub proposer: Pubkey,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingAction {
+    pub const SEED: &'static [u8] = b"action";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AdminActionKind {
+    /// Create an active ServerSigner for `target`
+    AddServerSigner,
+    /// Propose `target` as emergency admin (still needs accept_emergency_admin)
+    ChangeEmergencyAdmin,
+    /// Propose `target` as authority (still needs accept_authority)
+    TransferAuthority,
+    /// Set Config.timelock_delay to `delay`
+    SetTimelockDelay,
+}
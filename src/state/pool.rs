@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Settlement pool registered by the authority
+/// Pool payments are only accepted into the vault of an active pool
+/// Seeds: ["pool", operator, token_mint]
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    /// Operator wallet for this pool (settlement service)
+    /// Can: withdraw_from_pool (together with Config.authority), unowed funds only
+    pub operator: Pubkey,
+    /// Token mint accepted by this pool
+    pub token_mint: Pubkey,
+    /// Program-owned vault token account (authority = this Pool PDA)
+    /// Seeds: ["pool_vault", pool]
+    pub vault: Pubkey,
+    /// Sum of all PoolLedger.owed for this pool
+    /// Vault funds up to this amount are reserved for settle_pool
+    pub total_owed: u64,
+    /// Whether this pool currently accepts payments
+    pub is_active: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Bump seed for vault PDA
+    pub vault_bump: u8,
+}
+
+impl Pool {
+    pub const SEED: &'static [u8] = b"pool";
+    pub const VAULT_SEED: &'static [u8] = b"pool_vault";
+}
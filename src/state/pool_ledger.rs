@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Per-pool settlement ledger entry for one tracked recipient on one destination chain
+/// Credited by pool payments, debited by settle_pool
+/// Seeds: ["pool_ledger", pool, destination_chain_id (u64 LE), address_format (u8), recipient]
+#[account]
+#[derive(InitSpace)]
+pub struct PoolLedger {
+    /// Pool this entry belongs to
+    pub pool: Pubkey,
+    /// Tracked recipient (EVM 20B zero-padded or SVM 32B), as in PoolPaymentParams
+    pub recipient: [u8; 32],
+    /// Chain the recipient address lives on
+    pub destination_chain_id: u64,
+    /// Format of `recipient`
+    pub address_format: AddressFormat,
+    /// Amount currently owed to the recipient (credited − settled)
+    pub owed: u64,
+    /// Lifetime amount credited by pool payments
+    pub total_credited: u64,
+    /// Lifetime amount paid out by settle_pool
+    pub total_settled: u64,
+    /// Incremented on every settlement — part of the signed settlement message (replay protection)
+    pub settlement_nonce: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PoolLedger {
+    pub const SEED: &'static [u8] = b"pool_ledger";
+}
+
+/// Address format of a pool payment's tracked recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AddressFormat {
+    /// EVM 20B address, left-padded with 12 zero bytes
+    Evm,
+    /// SVM 32B address
+    Svm,
+}
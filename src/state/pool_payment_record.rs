@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use super::AddressFormat;
+
+/// Record of a single pool payment, created by the pool payment instructions
+/// Also prevents the same payment_id from being paid into a pool twice, and a
+/// payment from being both settled and refunded
+/// Seeds: ["pool_payment", pool, payment_id]
+#[account]
+#[derive(InitSpace)]
+pub struct PoolPaymentRecord {
+    /// Pool the payment was made into
+    pub pool: Pubkey,
+    pub payment_id: [u8; 32],
+    /// Original sender (token owner)
+    pub sender: Pubkey,
+    /// Token account the payment was pulled from (refund destination)
+    pub sender_token_account: Pubkey,
+    /// Tracked recipient — with the chain and format, the PoolLedger key credited with `amount`
+    pub recipient: [u8; 32],
+    pub destination_chain_id: u64,
+    pub address_format: AddressFormat,
+    /// Amount received by the vault — amount + service fee net of any Token-2022
+    /// transfer fee, excluding the relayer fee (returned in full on refund)
+    pub vault_amount: u64,
+    /// Amount credited to the recipient's ledger entry (net of the transfer fee in Net mode)
+    pub amount: u64,
+    pub status: PoolPaymentStatus,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PoolPaymentRecord {
+    pub const SEED: &'static [u8] = b"pool_payment";
+    /// Maximum number of payments paid out by one settle_pool
+    pub const MAX_SETTLEMENT_PAYMENTS: usize = 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PoolPaymentStatus {
+    /// Funds are in the pool vault, owed to the recipient
+    Paid,
+    /// Paid out to the recipient by settle_pool (no longer refundable)
+    Settled,
+    /// Funds were returned to the sender by refund_pool_payment
+    Refunded,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Delegated admin role, granted and revoked by Config.authority
+/// Lets ops wallets perform a subset of admin tasks without the master key
+/// Seeds: ["role", role (u8), member]
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAssignment {
+    pub role: Role,
+    /// Wallet holding the role
+    pub member: Pubkey,
+    /// Authority that granted the role
+    pub granted_by: Pubkey,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RoleAssignment {
+    pub const SEED: &'static [u8] = b"role";
+
+    /// Whether `assignment` (if provided) grants `role` to `member`
+    pub fn grants(assignment: Option<&RoleAssignment>, role: Role, member: &Pubkey) -> bool {
+        assignment.is_some_and(|assignment| assignment.role == role && assignment.member == *member)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    /// pause / unpause (alongside the emergency admin)
+    Pauser,
+    /// remove_server_signer, emergency_remove_server_signer
+    SignerManager,
+    /// Global and per-mint fee configs
+    FeeManager,
+    /// create_pool, set_pool_active
+    PoolOperator,
+    /// Merchant registry (register/update merchants, status, settlement accounts)
+    MerchantManager,
+}
@@ -6,14 +6,40 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct ServerSigner {
+    /// Layout version (ServerSigner::CURRENT_VERSION), upgraded by migrate_server_signer
+    pub version: u8,
     /// The signer's public key (Ed25519)
     pub signer: Pubkey,
-    /// Whether this signer is currently active
+    /// Whether this signer is currently active (deactivate / reactivate_server_signer)
     pub is_active: bool,
+    /// Temporarily paused by the emergency admin / Pauser (payments it signs are rejected)
+    pub paused: bool,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Unix timestamp of the last status change (add, (de/re)activate, pause)
+    /// 0 if unknown (migrated from version 0)
+    pub updated_at: i64,
+    /// Wallet that made the last status change (Pubkey::default() if unknown)
+    pub updated_by: Pubkey,
+    /// Reserved for future fields (new fields take bytes from here; the version 1
+    /// reserve is 64 bytes, 40 of them now used by updated_at / updated_by)
+    pub reserved: [u8; 24],
 }
 
 impl ServerSigner {
     pub const SEED: &'static [u8] = b"server_signer";
+    pub const CURRENT_VERSION: u8 = 1;
+}
+
+/// Pre-versioning ServerSigner layout (version 0), read by migrate_server_signer
+#[derive(AnchorDeserialize)]
+pub struct LegacyServerSigner {
+    pub signer: Pubkey,
+    pub is_active: bool,
+    pub bump: u8,
+}
+
+impl LegacyServerSigner {
+    /// Account size including the discriminator
+    pub const LEN: usize = 8 + 32 + 1 + 1;
 }
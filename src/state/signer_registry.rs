@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PaymentError;
+
+/// Enumerable list of registered server signers, kept in sync with the
+/// ["server_signer", pubkey] PDAs by every add / remove / (de/re)activate path
+/// Seeds: ["signer_registry"]
+#[account]
+#[derive(InitSpace)]
+pub struct SignerRegistry {
+    #[max_len(16)]
+    pub signers: Vec<SignerEntry>,
+    /// Configurable cap on registered signers (≤ MAX_SIGNERS)
+    pub max_signers: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SignerRegistry {
+    pub const SEED: &'static [u8] = b"signer_registry";
+    /// Account capacity
+    pub const MAX_SIGNERS: u8 = 16;
+    /// max_signers set at initialization
+    pub const DEFAULT_MAX_SIGNERS: u8 = 8;
+
+    pub fn add(&mut self, signer: Pubkey) -> Result<()> {
+        require!(
+            self.signers.len() < self.max_signers as usize,
+            PaymentError::TooManyServerSigners
+        );
+        require!(
+            !self.signers.iter().any(|entry| entry.signer == signer),
+            PaymentError::SignerAlreadyRegistered
+        );
+        self.signers.push(SignerEntry {
+            signer,
+            is_active: true,
+        });
+        Ok(())
+    }
+
+    pub fn remove(&mut self, signer: &Pubkey) {
+        self.signers.retain(|entry| entry.signer != *signer);
+    }
+
+    pub fn set_active(&mut self, signer: &Pubkey, is_active: bool) {
+        if let Some(entry) = self
+            .signers
+            .iter_mut()
+            .find(|entry| entry.signer == *signer)
+        {
+            entry.is_active = is_active;
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SignerEntry {
+    pub signer: Pubkey,
+    /// Mirrors ServerSigner.is_active
+    pub is_active: bool,
+}
@@ -0,0 +1,517 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  AccountMeta,
+  ComputeBudgetProgram,
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  createAccount,
+  createInitializeMintInstruction,
+  createInitializeTransferHookInstruction,
+  getAccount,
+  getMintLen,
+  mintTo,
+} from "@solana/spl-token";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+
+import { SettoPayment } from "../target/types/setto_payment";
+import { SampleTransferHook } from "../target/types/sample_transfer_hook";
+
+const MEMO_PROGRAM_ID = new PublicKey(
+  "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+);
+const DESTINATION_CHAIN_ID = new BN(101);
+const SVM = 1;
+
+const u8 = (value: number) => Buffer.from([value]);
+const u64 = (value: BN) => value.toArrayLike(Buffer, "le", 8);
+
+// Direct payment message (see build_direct_payment_message)
+function directPaymentMessage(fields: {
+  paymentId: Buffer;
+  sender: PublicKey;
+  recipient: PublicKey;
+  feeWallet: PublicKey;
+  mint: PublicKey;
+  totalAmount: BN;
+  amount: BN;
+  protocolFee: BN;
+  relayer: PublicKey;
+  relayerFee: BN;
+  transferFeeMode: number;
+  payer: PublicKey;
+  gasTank: PublicKey;
+  gasReimbursement: BN;
+  deadline: BN;
+}): Buffer {
+  return Buffer.concat([
+    fields.paymentId,
+    fields.sender.toBuffer(),
+    fields.recipient.toBuffer(),
+    fields.feeWallet.toBuffer(),
+    fields.mint.toBuffer(),
+    u64(fields.totalAmount),
+    u64(fields.amount),
+    u64(fields.protocolFee),
+    fields.relayer.toBuffer(),
+    u64(fields.relayerFee),
+    u8(fields.transferFeeMode),
+    fields.payer.toBuffer(),
+    fields.gasTank.toBuffer(),
+    u64(fields.gasReimbursement),
+    u64(fields.deadline),
+    u8(0), // referenceLen
+    u8(0), // referenceKeyCount
+  ]);
+}
+
+// Pool payment message (see build_pool_payment_message)
+function poolPaymentMessage(fields: {
+  paymentId: Buffer;
+  sender: PublicKey;
+  pool: PublicKey;
+  recipient: Buffer;
+  mint: PublicKey;
+  totalAmount: BN;
+  amount: BN;
+  serviceFee: BN;
+  destinationChainId: BN;
+  addressFormat: number;
+  relayer: PublicKey;
+  relayerFee: BN;
+  transferFeeMode: number;
+  deadline: BN;
+}): Buffer {
+  return Buffer.concat([
+    fields.paymentId,
+    fields.sender.toBuffer(),
+    fields.pool.toBuffer(),
+    fields.recipient,
+    fields.mint.toBuffer(),
+    u64(fields.totalAmount),
+    u64(fields.amount),
+    u64(fields.serviceFee),
+    u64(fields.destinationChainId),
+    u8(fields.addressFormat),
+    fields.relayer.toBuffer(),
+    u64(fields.relayerFee),
+    u8(fields.transferFeeMode),
+    u64(fields.deadline),
+    u8(0), // referenceLen
+    u8(0), // referenceKeyCount
+  ]);
+}
+
+describe("transfer hook", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const connection = provider.connection;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+
+  const program = anchor.workspace.SettoPayment as Program<SettoPayment>;
+  const hook = anchor.workspace
+    .SampleTransferHook as Program<SampleTransferHook>;
+
+  const emergencyAdmin = Keypair.generate();
+  const serverSigner = Keypair.generate();
+  const feeWallet = Keypair.generate();
+  const sender = Keypair.generate();
+  const merchant = Keypair.generate();
+  const poolOperator = Keypair.generate();
+  const mint = Keypair.generate();
+
+  const pda = (programId: PublicKey, ...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, programId)[0];
+
+  const counter = pda(
+    hook.programId,
+    Buffer.from("counter"),
+    mint.publicKey.toBuffer()
+  );
+  const extraAccountMetaList = pda(
+    hook.programId,
+    Buffer.from("extra-account-metas"),
+    mint.publicKey.toBuffer()
+  );
+  const serverSignerAccount = pda(
+    program.programId,
+    Buffer.from("server_signer"),
+    serverSigner.publicKey.toBuffer()
+  );
+  const pool = pda(
+    program.programId,
+    Buffer.from("pool"),
+    poolOperator.publicKey.toBuffer(),
+    mint.publicKey.toBuffer()
+  );
+  const poolVault = pda(
+    program.programId,
+    Buffer.from("pool_vault"),
+    pool.toBuffer()
+  );
+
+  let senderTokenAccount: PublicKey;
+  let merchantTokenAccount: PublicKey;
+
+  // Accounts Token-2022 needs to invoke the hook, forwarded as remaining accounts
+  const hookAccounts = (): AccountMeta[] => [
+    { pubkey: counter, isSigner: false, isWritable: true },
+    { pubkey: extraAccountMetaList, isSigner: false, isWritable: false },
+    { pubkey: hook.programId, isSigner: false, isWritable: false },
+  ];
+
+  const hookCount = async () =>
+    (await hook.account.counter.fetch(counter)).count.toNumber();
+
+  const deadline = () => new BN(Math.floor(Date.now() / 1000) + 300);
+
+  // Ed25519 verification instruction plus the signature it carries
+  function signMessage(message: Buffer) {
+    const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: serverSigner.secretKey,
+      message,
+    });
+    // Header (16) + public key (32), then the 64-byte signature
+    const signature = Array.from(ed25519Ix.data.subarray(48, 112));
+    return { ed25519Ix, signature };
+  }
+
+  async function send(
+    ed25519Ix: TransactionInstruction,
+    paymentIx: TransactionInstruction
+  ) {
+    const tx = new Transaction().add(
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+      ed25519Ix,
+      paymentIx
+    );
+    return provider.sendAndConfirm(tx, [sender]);
+  }
+
+  async function directPayment(remainingAccounts: AccountMeta[]) {
+    const paymentId = randomBytes(32);
+    const amount = new BN(1_000_000);
+    const params = {
+      paymentId: Array.from(paymentId),
+      totalAmount: amount,
+      amount,
+      protocolFee: new BN(0),
+      feeWallet: feeWallet.publicKey,
+      relayer: PublicKey.default,
+      relayerFee: new BN(0),
+      transferFeeMode: { net: {} },
+      gasReimbursement: new BN(0),
+      reference: Buffer.alloc(0),
+      referenceKeyCount: 0,
+      deadline: deadline(),
+      serverSigner: serverSigner.publicKey,
+      serverSignature: [] as number[],
+    };
+
+    const { ed25519Ix, signature } = signMessage(
+      directPaymentMessage({
+        paymentId,
+        sender: sender.publicKey,
+        recipient: merchant.publicKey,
+        feeWallet: feeWallet.publicKey,
+        mint: mint.publicKey,
+        totalAmount: params.totalAmount,
+        amount: params.amount,
+        protocolFee: params.protocolFee,
+        relayer: params.relayer,
+        relayerFee: params.relayerFee,
+        transferFeeMode: 0,
+        payer: payer.publicKey,
+        gasTank: PublicKey.default,
+        gasReimbursement: params.gasReimbursement,
+        deadline: params.deadline,
+      })
+    );
+    params.serverSignature = signature;
+
+    const paymentIx = await program.methods
+      .processDirectPayment(params)
+      .accountsPartial({
+        payer: payer.publicKey,
+        sender: sender.publicKey,
+        serverSignerAccount,
+        tokenMint: mint.publicKey,
+        senderTokenAccount,
+        recipientTokenAccount: merchantTokenAccount,
+        feeWalletTokenAccount: null,
+        merchant: null,
+        gasTank: null,
+        // Only required with a gas tank
+        paymentRecord: null,
+        relayerTokenAccount: null,
+        memoProgram: MEMO_PROGRAM_ID,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+    return send(ed25519Ix, paymentIx);
+  }
+
+  async function poolPayment(remainingAccounts: AccountMeta[]) {
+    const paymentId = randomBytes(32);
+    const recipient = merchant.publicKey.toBuffer();
+    const amount = new BN(1_000_000);
+    const serviceFee = new BN(10_000);
+    const params = {
+      paymentId: Array.from(paymentId),
+      totalAmount: amount.add(serviceFee),
+      amount,
+      serviceFee,
+      recipient: Array.from(recipient),
+      destinationChainId: DESTINATION_CHAIN_ID,
+      addressFormat: { svm: {} },
+      relayer: PublicKey.default,
+      relayerFee: new BN(0),
+      transferFeeMode: { net: {} },
+      reference: Buffer.alloc(0),
+      referenceKeyCount: 0,
+      deadline: deadline(),
+      serverSigner: serverSigner.publicKey,
+      serverSignature: [] as number[],
+    };
+
+    const { ed25519Ix, signature } = signMessage(
+      poolPaymentMessage({
+        paymentId,
+        sender: sender.publicKey,
+        pool,
+        recipient,
+        mint: mint.publicKey,
+        totalAmount: params.totalAmount,
+        amount: params.amount,
+        serviceFee: params.serviceFee,
+        destinationChainId: DESTINATION_CHAIN_ID,
+        addressFormat: SVM,
+        relayer: params.relayer,
+        relayerFee: params.relayerFee,
+        transferFeeMode: 0,
+        deadline: params.deadline,
+      })
+    );
+    params.serverSignature = signature;
+
+    const paymentIx = await program.methods
+      .processPoolPayment(params)
+      .accountsPartial({
+        payer: payer.publicKey,
+        sender: sender.publicKey,
+        serverSignerAccount,
+        tokenMint: mint.publicKey,
+        senderTokenAccount,
+        pool,
+        poolVault,
+        poolLedger: pda(
+          program.programId,
+          Buffer.from("pool_ledger"),
+          pool.toBuffer(),
+          u64(DESTINATION_CHAIN_ID),
+          u8(SVM),
+          recipient
+        ),
+        paymentRecord: pda(
+          program.programId,
+          Buffer.from("pool_payment"),
+          pool.toBuffer(),
+          paymentId
+        ),
+        relayerTokenAccount: null,
+        memoProgram: MEMO_PROGRAM_ID,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+    return send(ed25519Ix, paymentIx);
+  }
+
+  before(async () => {
+    await program.methods
+      .initialize()
+      .accountsPartial({
+        authority: payer.publicKey,
+        emergencyAdmin: emergencyAdmin.publicKey,
+        serverSigner: serverSigner.publicKey,
+        serverSignerAccount,
+      })
+      .rpc();
+
+    await program.methods
+      .setFeeConfig({
+        feeWallet: feeWallet.publicKey,
+        feeBps: 0,
+        minFee: new BN(0),
+        maxFee: new BN(0),
+      })
+      .accountsPartial({ manager: payer.publicKey, roleAssignment: null })
+      .rpc();
+
+    // Token-2022 mint whose transfers invoke the sample hook
+    const mintLen = getMintLen([ExtensionType.TransferHook]);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space: mintLen,
+          lamports: await connection.getMinimumBalanceForRentExemption(mintLen),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferHookInstruction(
+          mint.publicKey,
+          payer.publicKey,
+          hook.programId,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(
+          mint.publicKey,
+          6,
+          payer.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [mint]
+    );
+
+    await hook.methods
+      .initializeExtraAccountMetaList()
+      .accountsPartial({
+        payer: payer.publicKey,
+        extraAccountMetaList,
+        mint: mint.publicKey,
+        counter,
+      })
+      .rpc();
+
+    await program.methods
+      .setMintConfig({
+        enabled: true,
+        minAmount: new BN(1),
+        maxAmount: new BN(1_000_000_000_000),
+        allowedKinds: 0b11,
+        allowDelegated: false,
+      })
+      .accountsPartial({
+        authority: payer.publicKey,
+        tokenMint: mint.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .createPool()
+      .accountsPartial({
+        manager: payer.publicKey,
+        operator: poolOperator.publicKey,
+        tokenMint: mint.publicKey,
+        pool,
+        poolVault,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        roleAssignment: null,
+      })
+      .rpc();
+
+    senderTokenAccount = await createAccount(
+      connection,
+      payer,
+      mint.publicKey,
+      sender.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    merchantTokenAccount = await createAccount(
+      connection,
+      payer,
+      mint.publicKey,
+      merchant.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      connection,
+      payer,
+      mint.publicKey,
+      senderTokenAccount,
+      payer,
+      100_000_000,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+  });
+
+  it("runs the hook on a direct payment", async () => {
+    const countBefore = await hookCount();
+
+    await directPayment(hookAccounts());
+
+    const recipient = await getAccount(
+      connection,
+      merchantTokenAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(Number(recipient.amount)).to.equal(1_000_000);
+    expect(await hookCount()).to.equal(countBefore + 1);
+  });
+
+  it("runs the hook on a pool payment", async () => {
+    const countBefore = await hookCount();
+
+    await poolPayment(hookAccounts());
+
+    const vault = await getAccount(
+      connection,
+      poolVault,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(Number(vault.amount)).to.equal(1_010_000);
+    expect(await hookCount()).to.equal(countBefore + 1);
+  });
+
+  it("rejects a direct payment without the hook's extra accounts", async () => {
+    const countBefore = await hookCount();
+
+    let failed = false;
+    try {
+      await directPayment([]);
+    } catch {
+      failed = true;
+    }
+
+    expect(failed).to.equal(true);
+    expect(await hookCount()).to.equal(countBefore);
+  });
+
+  it("rejects a pool payment without the hook's extra accounts", async () => {
+    const countBefore = await hookCount();
+
+    let failed = false;
+    try {
+      await poolPayment([]);
+    } catch {
+      failed = true;
+    }
+
+    expect(failed).to.equal(true);
+    expect(await hookCount()).to.equal(countBefore);
+  });
+});